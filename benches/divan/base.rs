@@ -1,5 +1,5 @@
 use divan::black_box;
-use mutringbuf::{HeapSplit, LocalHeapRB, MRBIterator};
+use mutringbuf::{FollowerIter, HeapSplit, LocalHeapRB, MRBIterator};
 
 const BUFFER_SIZE: usize = 4096;
 const BATCH_SIZE: usize = 100;
@@ -10,7 +10,7 @@ fn main() {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_local(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -23,7 +23,7 @@ fn push_pop_local(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_shared(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -36,7 +36,7 @@ fn push_pop_shared(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_x100(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
 
     let (mut prod, mut cons) = buf.split();
 
@@ -54,7 +54,7 @@ fn push_pop_x100(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_x100_local(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
 
     let (mut prod, mut cons) = buf.split();
 
@@ -72,7 +72,7 @@ fn push_pop_x100_local(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_x100_heap(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
 
     let (mut prod, mut cons) = buf.split();
 
@@ -90,7 +90,7 @@ fn push_pop_x100_heap(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn push_pop_work(b: divan::Bencher) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut work, mut cons) = buf.split_mut();
 
     #[inline]
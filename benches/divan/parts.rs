@@ -9,7 +9,7 @@ fn main() {
 
 #[divan::bench(sample_size = 100000)]
 fn advance(b: divan::Bencher) {
-    let buf = ConcurrentHeapRB::default(BUFFER_SIZE);
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -26,7 +26,7 @@ fn advance(b: divan::Bencher) {
 
 #[divan::bench(sample_size = 100000)]
 fn available(b: divan::Bencher) {
-    let buf = ConcurrentHeapRB::default(BUFFER_SIZE);
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[0; BUFFER_SIZE / 4]);
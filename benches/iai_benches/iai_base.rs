@@ -9,7 +9,7 @@ const BATCH_SIZE: usize = 100;
 #[library_benchmark]
 #[bench::long(1000)]
 pub fn push_pop_local(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -23,7 +23,7 @@ pub fn push_pop_local(value: u64) {
 #[library_benchmark]
 #[bench::long(1000)]
 pub fn push_pop_shared(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -37,7 +37,7 @@ pub fn push_pop_shared(value: u64) {
 #[library_benchmark]
 #[bench::long(1000)]
 pub fn push_pop_x100_local(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
@@ -55,7 +55,7 @@ pub fn push_pop_x100_local(value: u64) {
 #[library_benchmark]
 #[bench::long(1000)]
 pub fn push_pop_x100(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]).unwrap();
@@ -73,7 +73,7 @@ pub fn push_pop_x100(value: u64) {
 #[library_benchmark]
 #[bench::long(1000)]
 fn slice_x10(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
@@ -89,7 +89,7 @@ fn slice_x10(value: u64) {
 #[library_benchmark]
 #[bench::long(1000)]
 fn slice_x100(value: u64) {
-    let buf = LocalHeapRB::default(BUFFER_SIZE);
+    let buf = LocalHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     prod.push_slice(&[1; BUFFER_SIZE / 2]);
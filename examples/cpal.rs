@@ -3,7 +3,7 @@ fn main() {
     use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
     use cpal::{InputCallbackInfo, OutputCallbackInfo, StreamConfig};
     use mutringbuf::HeapSplit;
-    use mutringbuf::{ConcurrentHeapRB, MRBIterator};
+    use mutringbuf::{ConcurrentHeapRB, FollowerIter, MRBIterator};
     use std::sync::Arc;
     use std::sync::atomic::AtomicBool;
     use std::sync::atomic::Ordering::Relaxed;
@@ -31,33 +31,18 @@ fn main() {
     let stop_worker = Arc::new(AtomicBool::new(false));
     let stop_clone = stop_worker.clone();
     let worker = thread::spawn(move || {
+        // The previous block, as it was before being processed.
         let mut acc = vec![0f32; delay_samples];
 
         while !stop_clone.load(Relaxed) {
-            #[cfg(not(feature = "vmem"))]
-            if let Some((h, t)) = work.get_workable_slice_exact(delay_samples) {
-                let len = h.len() + t.len();
-                h.swap_with_slice(&mut acc[..h.len()]);
-                t.swap_with_slice(&mut acc[h.len()..]);
-
-                for (v, w) in h.iter_mut().chain(t).zip(&acc) {
-                    *v *= DECAY;
-                    *v += *w;
+            if let Some(mut block) = work.get_workable_slice_exact(delay_samples) {
+                for (i, prev) in acc.iter_mut().enumerate() {
+                    let raw = block[i];
+                    block[i] += *prev * DECAY;
+                    *prev = raw;
                 }
 
-                unsafe { work.advance(len) };
-            }
-            #[cfg(feature = "vmem")]
-            if let Some(r) = work.get_workable_slice_exact(delay_samples) {
-                let len = r.len();
-                r.swap_with_slice(&mut acc);
-
-                for (v, w) in r.iter_mut().zip(&acc) {
-                    *v *= DECAY;
-                    *v += *w;
-                }
-
-                unsafe { work.advance(len) };
+                unsafe { work.advance(delay_samples) };
             }
         }
 
@@ -84,16 +69,8 @@ fn main() {
             move |slice: &mut [f32], _info: &OutputCallbackInfo| {
                 let len = slice.len();
 
-                #[cfg(not(feature = "vmem"))]
-                if let Some((h, t)) = cons.peek_slice(len) {
-                    slice[..h.len()].copy_from_slice(h);
-                    slice[h.len()..].copy_from_slice(t);
-
-                    unsafe { cons.advance(len) };
-                }
-                #[cfg(feature = "vmem")]
                 if let Some(r) = cons.peek_slice(len) {
-                    slice.copy_from_slice(r);
+                    r.copy_to_slice(slice);
 
                     unsafe { cons.advance(len) };
                 } else {
@@ -67,14 +67,8 @@ fn main() {
         let (mut cons, mut consumed) = consumer.join().unwrap();
 
         // Consume the remaining part of the buffer
-        #[cfg(feature = "vmem")]
         if let Some(res) = cons.peek_available() {
-            consumed.extend_from_slice(res);
-        }
-        #[cfg(not(feature = "vmem"))]
-        if let Some((head, tail)) = cons.peek_available() {
-            consumed.extend_from_slice(head);
-            consumed.extend_from_slice(tail);
+            consumed.extend(res);
         }
 
         assert_eq!(produced, consumed);
@@ -29,11 +29,10 @@ async fn main() {
     let slice: Vec<i32> = (0..BUFFER_SIZE as i32 / 2).collect();
     as_prod.push_slice(&slice).await;
 
-    #[cfg(not(feature = "vmem"))]
-    if let Some((h, t)) = as_work.get_workable_slice_avail().await {
-        let len = h.len() + t.len();
+    if let Some(mut slices) = as_work.get_workable_slice_avail().await {
+        let len = slices.len();
 
-        for x in h.iter_mut().chain(t) {
+        for x in slices.iter_mut() {
             *x += 1;
         }
 
@@ -41,28 +40,9 @@ async fn main() {
             as_work.advance(len);
         }
     }
-    #[cfg(feature = "vmem")]
-    if let Some(r) = as_work.get_workable_slice_avail().await {
-        let len = r.len();
 
-        for x in r {
-            *x += 1;
-        }
-
-        unsafe {
-            as_work.advance(len);
-        }
-    }
-
-    #[cfg(not(feature = "vmem"))]
-    if let Some((h, t)) = as_cons.peek_available().await {
-        for (x, y) in h.iter().chain(t).zip(&slice) {
-            assert_eq!(*x, y + 1);
-        }
-    }
-    #[cfg(feature = "vmem")]
-    if let Some(r) = as_cons.peek_available().await {
-        for (x, y) in r.iter().zip(&slice) {
+    if let Some(slices) = as_cons.peek_available().await {
+        for (x, y) in slices.iter().zip(&slice) {
             assert_eq!(*x, y + 1);
         }
     }
@@ -2,12 +2,12 @@ use core::marker::PhantomData;
 use core::task::Waker;
 
 use crate::Storage;
-use crate::iterators::ConsIter;
-use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
+use crate::iterators::async_iterators::async_macros::{gen_common_futs_fn, gen_workable_futs_fn};
 use crate::iterators::async_iterators::{AsyncIterator, MRBFuture};
-use crate::iterators::iterator_trait::MutableSlice;
-use crate::iterators::iterator_trait::{MRBIterator, NonMutableSlice};
+use crate::iterators::iterator_trait::FollowerIter;
+use crate::iterators::iterator_trait::{MRBIterator, Role};
 use crate::iterators::util_macros::delegate;
+use crate::iterators::{ConsIter, SlicePair};
 use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
 
@@ -20,20 +20,26 @@ pub struct AsyncConsIter<'buf, S: Storage, const W: bool> {
 }
 unsafe impl<S: Storage, const W: bool> Send for AsyncConsIter<'_, S, W> {}
 
+impl<S: Storage, const W: bool> core::fmt::Debug for AsyncConsIter<'_, S, W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AsyncConsIter").field(&self.inner).finish()
+    }
+}
+
 impl<'buf, S: Storage, const W: bool> AsyncIterator<'buf> for AsyncConsIter<'buf, S, W> {
     type I = ConsIter<'buf, AsyncMutRingBuf<S>, W>;
     type S = S;
 
     fn register_waker(&self, waker: &Waker) {
-        (*self.waker).cons_waker.register(waker);
+        self.waker.cons_waker.register(waker);
     }
 
     fn take_waker(&self) -> Option<Waker> {
-        (*self.waker).cons_waker.take()
+        self.waker.cons_waker.take()
     }
 
     fn wake_next(&self) {
-        (*self.waker).prod_waker.wake()
+        self.waker.wake(Role::Prod)
     }
 
     #[inline]
@@ -54,9 +60,18 @@ impl<'buf, S: Storage, const W: bool> AsyncIterator<'buf> for AsyncConsIter<'buf
 
 impl<'buf, S: Storage<Item = T>, T, const W: bool> AsyncConsIter<'buf, S, W> {
     gen_common_futs_fn!( 'buf );
+    gen_workable_futs_fn!( 'buf );
 
     delegate!(ConsIter, pub fn reset_index(&(mut) self));
 
+    /// Same as [`ConsIter::history`].
+    pub fn history<'b>(&self, count: usize) -> Option<SlicePair<'b, T>>
+    where
+        T: Copy,
+    {
+        self.inner().history(count)
+    }
+
     /// Async version of [`ConsIter::peek_ref`].
     pub fn peek_ref<'b>(&'b mut self) -> MRBFuture<'buf, 'b, Self, (), &'b T, true> {
         #[inline]
@@ -76,16 +91,57 @@ impl<'buf, S: Storage<Item = T>, T, const W: bool> AsyncConsIter<'buf, S, W> {
         }
     }
 
+    /// Async version of [`ConsIter::peek_at`].
+    pub fn peek_at<'b>(
+        &'b mut self,
+        offset: usize,
+    ) -> MRBFuture<'buf, 'b, Self, usize, &'b T, true> {
+        #[inline]
+        fn f<'b, S: Storage<Item = T>, const W: bool, T>(
+            s: &mut AsyncConsIter<S, W>,
+            offset: &mut usize,
+        ) -> Option<&'b T> {
+            s.inner_mut().peek_at(*offset)
+        }
+
+        MRBFuture {
+            iter: self,
+            p: Some(offset),
+            f_r: Some(f),
+            f_m: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Async version of [`ConsIter::peek_window`].
+    pub fn peek_window<'b>(&'b mut self) -> MRBFuture<'buf, 'b, Self, (), SlicePair<'b, T>, true> {
+        #[inline]
+        fn f<'b, S: Storage<Item = T>, const W: bool, T>(
+            s: &mut AsyncConsIter<S, W>,
+            _: &mut (),
+        ) -> Option<SlicePair<'b, T>> {
+            s.inner_mut().peek_window()
+        }
+
+        MRBFuture {
+            iter: self,
+            p: Some(()),
+            f_r: Some(f),
+            f_m: None,
+            phantom: PhantomData,
+        }
+    }
+
     /// Async version of [`ConsIter::peek_slice`].
     pub fn peek_slice<'b>(
         &'b mut self,
         count: usize,
-    ) -> MRBFuture<'buf, 'b, Self, usize, NonMutableSlice<'b, T>, true> {
+    ) -> MRBFuture<'buf, 'b, Self, usize, SlicePair<'b, T>, true> {
         #[inline]
         fn f<'b, S: Storage<Item = T>, const W: bool, T>(
             s: &mut AsyncConsIter<S, W>,
             count: &mut usize,
-        ) -> Option<NonMutableSlice<'b, T>> {
+        ) -> Option<SlicePair<'b, T>> {
             s.inner_mut().peek_slice(*count)
         }
 
@@ -101,12 +157,12 @@ impl<'buf, S: Storage<Item = T>, T, const W: bool> AsyncConsIter<'buf, S, W> {
     /// Async version of [`ConsIter::peek_available`].
     pub fn peek_available<'b>(
         &'b mut self,
-    ) -> MRBFuture<'buf, 'b, Self, (), NonMutableSlice<'b, T>, true> {
+    ) -> MRBFuture<'buf, 'b, Self, (), SlicePair<'b, T>, true> {
         #[inline]
         fn f<'b, S: Storage<Item = T>, const W: bool, T>(
             s: &mut AsyncConsIter<S, W>,
             _: &mut (),
-        ) -> Option<NonMutableSlice<'b, T>> {
+        ) -> Option<SlicePair<'b, T>> {
             s.inner_mut().peek_available()
         }
 
@@ -120,8 +176,6 @@ impl<'buf, S: Storage<Item = T>, T, const W: bool> AsyncConsIter<'buf, S, W> {
     }
 
     /// Async version of [`ConsIter::pop`].
-    /// # Safety
-    /// See above.
     pub fn pop<'b>(&'b mut self) -> MRBFuture<'buf, 'b, Self, (), T, true> {
         #[inline]
         fn f<S: Storage<Item = T>, const W: bool, T>(
@@ -142,23 +196,10 @@ impl<'buf, S: Storage<Item = T>, T, const W: bool> AsyncConsIter<'buf, S, W> {
 
     /// Async version of [`ConsIter::pop_move`].
     /// # Safety
-    /// See above.
+    /// See [`ConsIter::pop_move`].
+    #[deprecated(since = "1.0.0", note = "use `pop` instead")]
     pub unsafe fn pop_move<'b>(&'b mut self) -> MRBFuture<'buf, 'b, Self, (), T, true> {
-        #[inline]
-        fn f<S: Storage<Item = T>, const W: bool, T>(
-            s: &mut AsyncConsIter<S, W>,
-            _: &mut (),
-        ) -> Option<T> {
-            unsafe { s.inner_mut().pop_move() }
-        }
-
-        MRBFuture {
-            iter: self,
-            p: Some(()),
-            f_r: Some(f),
-            f_m: None,
-            phantom: PhantomData,
-        }
+        self.pop()
     }
 
     /// Async version of [`ConsIter::copy_item`].
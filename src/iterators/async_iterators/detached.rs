@@ -1,9 +1,12 @@
 use crate::iterators::async_iterators::AsyncIterator;
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ptr;
 
 #[allow(unused_imports)]
 use crate::iterators::Detached;
-use crate::iterators::iterator_trait::PrivateMRBIterator;
+use crate::iterators::iterator_trait::{FollowerIter, PrivateMRBIterator};
+use crate::iterators::{SeekError, SeekFrom};
 use crate::{MRBIterator, MutRB};
 
 #[doc = r##"
@@ -16,6 +19,12 @@ pub struct AsyncDetached<'buf, I: AsyncIterator<'buf>, B: MutRB> {
 
 unsafe impl<'buf, I: AsyncIterator<'buf>, B: MutRB> Send for AsyncDetached<'buf, I, B> {}
 
+impl<'buf, I: AsyncIterator<'buf>, B: MutRB> Drop for AsyncDetached<'buf, I, B> {
+    fn drop(&mut self) {
+        unsafe { self.inner.inner().release_local() };
+    }
+}
+
 impl<'buf, B: MutRB<Item = T>, T, I: AsyncIterator<'buf>> AsyncDetached<'buf, I, B> {
     /// Creates [`Self`] from an [`AsyncWorkIter`].
     pub(crate) fn from_iter(iter: I) -> AsyncDetached<'buf, I, B> {
@@ -28,12 +37,34 @@ impl<'buf, B: MutRB<Item = T>, T, I: AsyncIterator<'buf>> AsyncDetached<'buf, I,
     /// Same as [`Detached::attach`].
     pub fn attach(self) -> I {
         self.sync_index();
-        self.inner
+
+        let this = ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.inner) }
     }
 
     /// Same as [`Detached::sync_index`].
     pub fn sync_index(&self) {
-        self.inner.inner().set_atomic_index(self.inner.index())
+        self.inner.inner().sync_atomic()
+    }
+
+    /// Same as [`Detached::position`].
+    pub fn position(&self) -> u64 {
+        self.inner.inner().position()
+    }
+
+    /// Same as [`Detached::total_pushed`].
+    pub fn total_pushed(&self) -> u64 {
+        self.inner.inner().total_pushed()
+    }
+
+    /// Same as [`Detached::total_worked`].
+    pub fn total_worked(&self) -> u64 {
+        self.inner.inner().total_worked()
+    }
+
+    /// Same as [`Detached::total_popped`].
+    pub fn total_popped(&self) -> u64 {
+        self.inner.inner().total_popped()
     }
 
     /// Same as [`Detached::advance`].
@@ -49,17 +80,29 @@ impl<'buf, B: MutRB<Item = T>, T, I: AsyncIterator<'buf>> AsyncDetached<'buf, I,
     /// # Safety
     /// Same as [`Detached::go_back`].
     pub unsafe fn go_back(&mut self, count: usize) {
-        let idx = self.inner.inner_mut().index();
-        let buf_len = self.inner.inner_mut().buf_len();
-
-        self.inner.inner_mut().set_local_index(match idx < count {
-            true => unsafe { buf_len.unchecked_sub(count).unchecked_sub(idx) },
-            false => unsafe { idx.unchecked_sub(count) },
-        });
-
-        let avail = self.inner.inner_mut().cached_avail();
-        self.inner
-            .inner_mut()
-            .set_cached_avail(unsafe { avail.unchecked_add(count) });
+        unsafe { self.inner.inner_mut().go_back_local(count) };
+    }
+
+    /// Same as [`Detached::offset_from_successor`].
+    pub fn offset_from_successor(&self) -> usize {
+        self.inner.inner().offset_from_succ()
+    }
+}
+
+impl<'buf, B: MutRB<Item = T>, T, I: AsyncIterator<'buf>> AsyncDetached<'buf, I, B>
+where
+    I::I: FollowerIter,
+{
+    /// Same as [`Detached::seek`].
+    pub fn seek(&mut self, pos: SeekFrom) -> Result<u64, SeekError> {
+        self.inner.inner_mut().seek_local(pos)
+    }
+
+    /// Same as [`Detached::rewind_to_successor`].
+    pub fn rewind_to_successor(&mut self) -> u64 {
+        let inner = self.inner.inner_mut();
+
+        inner.rewind_local();
+        inner._position()
     }
 }
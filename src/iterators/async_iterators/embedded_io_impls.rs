@@ -0,0 +1,85 @@
+use crate::Storage;
+use crate::iterators::async_iterators::{AsyncIterator, MRBFuture};
+use crate::iterators::{AsyncConsIter, AsyncProdIter};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use embedded_io_async::{BufRead, ErrorType, Read, Write};
+
+/// Returns a future resolving to the number of available items, as soon as there is at least one.
+fn wait_available<'buf, 'b, I: AsyncIterator<'buf>>(
+    iter: &'b mut I,
+) -> MRBFuture<'buf, 'b, I, (), usize, true> {
+    #[inline]
+    fn f<'buf, I: AsyncIterator<'buf>>(s: &mut I, _: &mut ()) -> Option<usize> {
+        Some(s.available()).filter(|&avail| avail > 0)
+    }
+
+    MRBFuture {
+        iter,
+        p: Some(()),
+        f_r: Some(f),
+        f_m: None,
+        phantom: PhantomData,
+    }
+}
+
+impl<S: Storage<Item = u8>, const W: bool> ErrorType for AsyncConsIter<'_, S, W> {
+    type Error = Infallible;
+}
+
+impl<S: Storage<Item = u8>, const W: bool> Read for AsyncConsIter<'_, S, W> {
+    /// Async version of [`embedded_io::Read::read`] for [`ConsIter`](crate::iterators::ConsIter).
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let count = wait_available(self).await.unwrap().min(buf.len());
+        self.inner_mut().copy_slice(&mut buf[..count]);
+        self.wake_next();
+        Ok(count)
+    }
+}
+
+impl<S: Storage<Item = u8>, const W: bool> BufRead for AsyncConsIter<'_, S, W> {
+    /// Async version of [`embedded_io::BufRead::fill_buf`] for [`ConsIter`](crate::iterators::ConsIter).
+    async fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        wait_available(self).await;
+
+        Ok(self.inner_mut().peek_available().unwrap().as_slices().0)
+    }
+
+    /// # Panics
+    /// If `amt` is greater than the number of available bytes.
+    fn consume(&mut self, amt: usize) {
+        assert!(
+            amt <= self.available(),
+            "cannot consume more than the available bytes"
+        );
+
+        unsafe { self.advance(amt) };
+    }
+}
+
+impl<S: Storage<Item = u8>, const W: bool> ErrorType for AsyncProdIter<'_, S, W> {
+    type Error = Infallible;
+}
+
+impl<'buf, S: Storage<Item = u8> + 'buf, const W: bool> Write for AsyncProdIter<'buf, S, W> {
+    /// Async version of [`embedded_io::Write::write`] for [`ProdIter`](crate::iterators::ProdIter).
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let count = wait_available(self).await.unwrap().min(buf.len());
+        self.inner_mut().push_slice(&buf[..count]);
+        self.wake_next();
+        Ok(count)
+    }
+
+    /// Does nothing, as pushed bytes are immediately visible to the next iterator.
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -17,6 +17,8 @@ use core::task::{Context, Poll, Waker};
 
 pub(crate) mod cons_iter;
 pub(crate) mod detached;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_io_impls;
 pub(crate) mod prod_iter;
 pub(crate) mod work_iter;
 
@@ -49,6 +51,13 @@ pub trait AsyncIterator<'buf> {
     delegate!(MRBIterator, fn alive_iters(&self) -> u8);
     delegate!(MRBIterator, fn index(&self) -> usize);
     delegate!(MRBIterator, fn available(&(mut) self) -> usize);
+    delegate!(MRBIterator, fn position(&self) -> u64);
+    delegate!(MRBIterator, fn total_pushed(&self) -> u64);
+    delegate!(MRBIterator, fn total_worked(&self) -> u64);
+    delegate!(MRBIterator, fn total_popped(&self) -> u64);
+    delegate!(MRBIterator, fn buffer_state(&self) -> crate::BufferState);
+    #[cfg(feature = "stats")]
+    delegate!(MRBIterator, fn stats(&self) -> crate::StatsSnapshot);
 }
 
 /// Future returned by methods in async iterators.
@@ -59,6 +68,7 @@ where
     iter: &'a mut I,
     p: Option<P>,
     f_r: Option<fn(&mut I, &mut P) -> Option<O>>,
+    #[allow(clippy::type_complexity)]
     f_m: Option<fn(&mut I, P) -> Result<O, P>>,
     phantom: PhantomData<&'buf ()>,
 }
@@ -105,9 +115,26 @@ impl<'buf, 'a, I: AsyncIterator<'buf>, P, O, const R: bool> Future
 pub(crate) mod async_macros {
     macro_rules! gen_common_futs_fn {
         ($LT: lifetime) => {
-            /// Async version of [`MRBIterator::get_workable`].
+            /// Same as [`MRBIterator::advance`], waking the next iterator.
+            ///
+            /// # Safety
+            /// Same as [`MRBIterator::advance`].
+            pub unsafe fn advance(&mut self, count: usize) {
+                unsafe {
+                    self.inner.advance(count);
+                }
+                self.wake_next();
+            }
+        };
+    }
+
+    pub(crate) use gen_common_futs_fn;
+
+    macro_rules! gen_workable_futs_fn {
+        ($LT: lifetime) => {
+            /// Async version of [`FollowerIter::get_workable`].
             pub fn get_workable<'b>(&'b mut self) -> MRBFuture<$LT, 'b, Self, (), &'b mut T, true> {
-                fn f<'buf, 'b, II: MRBIterator<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
                     s: &mut I,
                     _: &mut (),
                 ) -> Option<&'b mut T> {
@@ -123,15 +150,56 @@ pub(crate) mod async_macros {
                 }
             }
 
-            /// Async version of [`MRBIterator::get_workable_slice_exact`].
+            /// Async version of [`FollowerIter::get_workable_at`].
+            pub fn get_workable_at<'b>(
+                &'b mut self,
+                offset: usize,
+            ) -> MRBFuture<$LT, 'b, Self, usize, &'b mut T, true> {
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+                    s: &mut I,
+                    offset: &mut usize,
+                ) -> Option<&'b mut T> {
+                    s.inner_mut().get_workable_at(*offset)
+                }
+
+                MRBFuture {
+                    iter: self,
+                    p: Some(offset),
+                    f_r: Some(f),
+                    f_m: None,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Async version of [`FollowerIter::get_workable_window`].
+            pub fn get_workable_window<'b>(
+                &'b mut self,
+            ) -> MRBFuture<$LT, 'b, Self, (), $crate::iterators::SlicePairMut<'b, T>, true> {
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+                    s: &mut I,
+                    _: &mut (),
+                ) -> Option<$crate::iterators::SlicePairMut<'b, T>> {
+                    s.inner_mut().get_workable_window()
+                }
+
+                MRBFuture {
+                    iter: self,
+                    p: Some(()),
+                    f_r: Some(f),
+                    f_m: None,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Async version of [`FollowerIter::get_workable_slice_exact`].
             pub fn get_workable_slice_exact<'b>(
                 &'b mut self,
                 count: usize,
-            ) -> MRBFuture<$LT, 'b, Self, usize, MutableSlice<'b, T>, true> {
-                fn f<'buf, 'b, II: MRBIterator<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+            ) -> MRBFuture<$LT, 'b, Self, usize, $crate::iterators::SlicePairMut<'b, T>, true> {
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
                     s: &mut I,
                     count: &mut usize,
-                ) -> Option<MutableSlice<'b, T>> {
+                ) -> Option<$crate::iterators::SlicePairMut<'b, T>> {
                     s.inner_mut().get_workable_slice_exact(*count)
                 }
 
@@ -144,14 +212,14 @@ pub(crate) mod async_macros {
                 }
             }
 
-            /// Async version of [`MRBIterator::get_workable_slice_avail`].
+            /// Async version of [`FollowerIter::get_workable_slice_avail`].
             pub fn get_workable_slice_avail<'b>(
                 &'b mut self,
-            ) -> MRBFuture<$LT, 'b, Self, (), MutableSlice<'b, T>, true> {
-                fn f<'buf, 'b, II: MRBIterator<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+            ) -> MRBFuture<$LT, 'b, Self, (), $crate::iterators::SlicePairMut<'b, T>, true> {
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
                     s: &mut I,
                     _: &mut (),
-                ) -> Option<MutableSlice<'b, T>> {
+                ) -> Option<$crate::iterators::SlicePairMut<'b, T>> {
                     s.inner_mut().get_workable_slice_avail()
                 }
 
@@ -164,15 +232,15 @@ pub(crate) mod async_macros {
                 }
             }
 
-            /// Async version of [`MRBIterator::get_workable_slice_multiple_of`].
+            /// Async version of [`FollowerIter::get_workable_slice_multiple_of`].
             pub fn get_workable_slice_multiple_of<'b>(
                 &'b mut self,
                 count: usize,
-            ) -> MRBFuture<$LT, 'b, Self, usize, MutableSlice<'b, T>, true> {
-                fn f<'buf, 'b, II: MRBIterator<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
+            ) -> MRBFuture<$LT, 'b, Self, usize, $crate::iterators::SlicePairMut<'b, T>, true> {
+                fn f<'buf, 'b, II: FollowerIter<Item = T>, I: AsyncIterator<'buf, I = II>, T>(
                     s: &mut I,
                     count: &mut usize,
-                ) -> Option<MutableSlice<'b, T>> {
+                ) -> Option<$crate::iterators::SlicePairMut<'b, T>> {
                     s.inner_mut().get_workable_slice_multiple_of(*count)
                 }
 
@@ -184,15 +252,8 @@ pub(crate) mod async_macros {
                     phantom: PhantomData,
                 }
             }
-
-            pub unsafe fn advance(&mut self, count: usize) {
-                unsafe {
-                    self.inner.advance(count);
-                }
-                self.wake_next();
-            }
         };
     }
 
-    pub(crate) use gen_common_futs_fn;
+    pub(crate) use gen_workable_futs_fn;
 }
@@ -2,11 +2,10 @@ use core::marker::PhantomData;
 use core::task::Waker;
 
 use crate::Storage;
-use crate::iterators::ProdIter;
 use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
 use crate::iterators::async_iterators::{AsyncIterator, MRBFuture};
-use crate::iterators::iterator_trait::MRBIterator;
-use crate::iterators::iterator_trait::MutableSlice;
+use crate::iterators::iterator_trait::{MRBIterator, Role};
+use crate::iterators::{ProdIter, SlicePairMut};
 use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
 
@@ -19,23 +18,29 @@ pub struct AsyncProdIter<'buf, S: Storage, const W: bool> {
 }
 unsafe impl<S: Storage, const W: bool> Send for AsyncProdIter<'_, S, W> {}
 
+impl<S: Storage, const W: bool> core::fmt::Debug for AsyncProdIter<'_, S, W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AsyncProdIter").field(&self.inner).finish()
+    }
+}
+
 impl<'buf, S: Storage, const W: bool> AsyncIterator<'buf> for AsyncProdIter<'buf, S, W> {
     type I = ProdIter<'buf, AsyncMutRingBuf<S>>;
     type S = S;
 
     fn register_waker(&self, waker: &Waker) {
-        (*self.waker).prod_waker.register(waker);
+        self.waker.prod_waker.register(waker);
     }
 
     fn take_waker(&self) -> Option<Waker> {
-        (*self.waker).prod_waker.take()
+        self.waker.prod_waker.take()
     }
 
     fn wake_next(&self) {
         if W {
-            (*self.waker).work_waker.wake()
+            self.waker.wake(Role::Work)
         } else {
-            (*self.waker).cons_waker.wake()
+            self.waker.wake(Role::Cons)
         }
     }
 
@@ -177,12 +182,12 @@ impl<'buf, S: Storage<Item = T> + 'buf, T, const W: bool> AsyncProdIter<'buf, S,
     pub unsafe fn get_next_slices_mut<'b>(
         &'b mut self,
         count: usize,
-    ) -> MRBFuture<'buf, 'b, Self, usize, MutableSlice<'b, T>, true> {
+    ) -> MRBFuture<'buf, 'b, Self, usize, SlicePairMut<'b, T>, true> {
         #[inline]
         fn f<'buf, 'b, S: Storage<Item = T> + 'buf, T, const W: bool>(
             s: &mut AsyncProdIter<'buf, S, W>,
             count: &mut usize,
-        ) -> Option<MutableSlice<'b, T>> {
+        ) -> Option<SlicePairMut<'b, T>> {
             unsafe { s.inner_mut().get_next_slices_mut(*count) }
         }
 
@@ -1,7 +1,8 @@
 use crate::Storage;
-use crate::iterators::async_iterators::async_macros::gen_common_futs_fn;
+use crate::iterators::async_iterators::async_macros::{gen_common_futs_fn, gen_workable_futs_fn};
 use crate::iterators::async_iterators::{AsyncIterator, MRBFuture};
-use crate::iterators::iterator_trait::MutableSlice;
+use crate::iterators::iterator_trait::FollowerIter;
+use crate::iterators::iterator_trait::Role;
 use crate::iterators::util_macros::delegate;
 use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
@@ -19,20 +20,26 @@ pub struct AsyncWorkIter<'buf, S: Storage> {
 }
 unsafe impl<S: Storage> Send for AsyncWorkIter<'_, S> {}
 
+impl<S: Storage> core::fmt::Debug for AsyncWorkIter<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AsyncWorkIter").field(&self.inner).finish()
+    }
+}
+
 impl<'buf, S: Storage> AsyncIterator<'buf> for AsyncWorkIter<'buf, S> {
     type I = WorkIter<'buf, AsyncMutRingBuf<S>>;
     type S = S;
 
     fn register_waker(&self, waker: &Waker) {
-        (*self.waker).work_waker.register(waker);
+        self.waker.work_waker.register(waker);
     }
 
     fn take_waker(&self) -> Option<Waker> {
-        (*self.waker).work_waker.take()
+        self.waker.work_waker.take()
     }
 
     fn wake_next(&self) {
-        (*self.waker).cons_waker.wake()
+        self.waker.wake(Role::Cons)
     }
 
     #[inline]
@@ -53,5 +60,6 @@ impl<'buf, S: Storage> AsyncIterator<'buf> for AsyncWorkIter<'buf, S> {
 
 impl<'buf, S: Storage<Item = T>, T> AsyncWorkIter<'buf, S> {
     gen_common_futs_fn!( 'buf );
+    gen_workable_futs_fn!( 'buf );
     delegate!(WorkIter, pub fn reset_index(&(mut) self));
 }
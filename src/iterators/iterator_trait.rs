@@ -1,22 +1,24 @@
-use crate::iterators::sync_iterators::detached::Detached;
+use crate::iterators::slice_pair::{SlicePair, SlicePairMut};
+use crate::iterators::sync_iterators::detached::{Detached, SeekError, SeekFrom};
+#[cfg(doc)]
+use crate::iterators::{ConsIter, ProdIter, WorkIter};
 use crate::ring_buffer::storage::MRBIndex;
+#[cfg(feature = "stats")]
+use crate::ring_buffer::variants::ring_buffer_trait::PrivateIterManager;
 use crate::ring_buffer::variants::ring_buffer_trait::{IterManager, StorageManager};
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
-use crate::{MutRB, Storage, UnsafeSyncCell};
-use core::mem::transmute;
+use crate::{MutRB, Storage};
+use core::cmp::Ordering;
+use core::fmt;
 use core::slice;
 
 /// Mutable slice returned by slice-specialised functions.
-#[cfg(feature = "vmem")]
-pub type MutableSlice<'a, T> = &'a mut [T];
-#[cfg(not(feature = "vmem"))]
-pub type MutableSlice<'a, T> = (&'a mut [T], &'a mut [T]);
+#[deprecated(since = "1.0.0", note = "use `SlicePairMut` instead")]
+pub type MutableSlice<'a, T> = SlicePairMut<'a, T>;
 
 /// Non-mutable slice returned by slice-specialised functions.
-#[cfg(feature = "vmem")]
-pub type NonMutableSlice<'a, T> = &'a [T];
-#[cfg(not(feature = "vmem"))]
-pub type NonMutableSlice<'a, T> = (&'a [T], &'a [T]);
+#[deprecated(since = "1.0.0", note = "use `SlicePair` instead")]
+pub type NonMutableSlice<'a, T> = SlicePair<'a, T>;
 
 /// Trait implemented by iterators.
 #[allow(private_bounds)]
@@ -64,6 +66,13 @@ pub trait MRBIterator: PrivateMRBIterator<Self::Item> {
         self.buffer().inner_len()
     }
 
+    /// Returns `true` if the buffer is mapped twice in a row, so that slices never wrap around its end.
+    /// See [`crate::VmemStorage`].
+    #[inline]
+    fn is_double_mapped(&self) -> bool {
+        self.buffer().inner().is_double_mapped()
+    }
+
     /// Returns how many iterators are still alive.
     fn alive_iters(&self) -> u8 {
         self.buffer().alive_iters()
@@ -85,65 +94,213 @@ pub trait MRBIterator: PrivateMRBIterator<Self::Item> {
         self.buffer().cons_index()
     }
 
+    /// Returns the absolute position of the iterator, i.e. the number of items it has passed over
+    /// since the buffer was split.
+    ///
+    /// Unlike [`Self::index`], this value never wraps around.
+    #[inline]
+    fn position(&self) -> u64 {
+        self._position()
+    }
+
+    /// Returns the number of items pushed into the buffer since it was split.
+    #[inline]
+    fn total_pushed(&self) -> u64 {
+        self.total_of(Role::Prod)
+    }
+
+    /// Returns the number of items worked since the buffer was split.
+    ///
+    /// The returned value is only meaningful if the buffer has been split with a [`WorkIter`].
+    #[inline]
+    fn total_worked(&self) -> u64 {
+        self.total_of(Role::Work)
+    }
+
+    /// Returns the number of items popped from the buffer since it was split.
+    #[inline]
+    fn total_popped(&self) -> u64 {
+        self.total_of(Role::Cons)
+    }
+
+    /// Returns a snapshot of the statistics of the buffer.
+    ///
+    /// To read them from another thread, see `stats_handle` (e.g. [`crate::iterators::ProdIter::stats_handle`]).
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    #[inline]
+    fn stats(&self) -> crate::StatsSnapshot {
+        self.buffer().stats().snapshot()
+    }
+
+    /// Returns a [`BufferState`](crate::BufferState), i.e. a snapshot of the indices of the buffer.
+    #[inline]
+    fn buffer_state(&self) -> crate::BufferState {
+        crate::BufferState::of(&**self.buffer())
+    }
+}
+
+/// Role of an iterator. Iterators are ordered as they follow each other: the consumer
+/// is always behind the worker, which is always behind the producer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) enum Role {
+    Cons,
+    Work,
+    Prod,
+}
+
+/// Formats the state of an iterator. Used by the [`Debug`](fmt::Debug) implementations of all iterators.
+pub(crate) fn debug_iter<T, I: PrivateMRBIterator<T>>(
+    iter: &I,
+    name: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let buf = iter.buffer();
+
+    f.debug_struct(name)
+        .field("role", &I::ROLE)
+        .field("index", &iter._index())
+        .field("position", &iter._position())
+        .field("cached_avail", &iter.cached_avail())
+        .field("buf_len", &buf.inner_len())
+        .field("alive_iters", &buf.alive_iters())
+        .finish()
+}
+
+/// Trait implemented by iterators following the producer, i.e. [`WorkIter`] and [`ConsIter`].
+///
+/// These iterators only pass over initialised items, so they can be moved freely within the
+/// region they are allowed to access, and the items they can access can be mutated in place.
+/// The producer, instead, only faces uninitialised locations, which can be written with
+/// [`ProdIter::push_with`] or [`ProdIter::reserve_uninit`].
+pub trait FollowerIter: MRBIterator {
     /// Returns a mutable references to the current value.
     ///
     /// <div class="warning">
     ///
-    /// Being these references, [`Self::advance()`] has to be called when done with the mutation
+    /// Being these references, [`MRBIterator::advance()`] has to be called when done with the mutation
     /// in order to move the iterator.
     /// </div>
     #[inline]
     fn get_workable<'a>(&mut self) -> Option<&'a mut Self::Item> {
-        self.next_ref_mut()
+        let ret = self.next_ref_mut();
+        self.record(ret)
+    }
+
+    /// Returns a mutable reference to the value `offset` items ahead of the current one, without
+    /// moving the iterator.
+    ///
+    /// Returns `None` if there are not more than `offset` available items.
+    /// <div class="warning">
+    ///
+    /// Being this a reference, [`MRBIterator::advance()`] has to be called when done with the mutation
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    fn get_workable_at<'a>(&mut self, offset: usize) -> Option<&'a mut Self::Item> {
+        self.next_ref_mut_at(offset)
+    }
+
+    /// Returns a mutable view over all available items, which can be indexed
+    /// regardless of where they wrap around the end of the buffer.
+    /// <div class="warning">
+    ///
+    /// Being these references, [`MRBIterator::advance()`] has to be called when done with the mutation
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    fn get_workable_window<'a>(&mut self) -> Option<SlicePairMut<'a, <Self as MRBIterator>::Item>> {
+        self.get_workable_slice_avail()
     }
 
-    /// Returns a tuple of mutable slice references, the sum of which with len equal to `count`.
+    /// Returns a [`SlicePairMut`] with len equal to `count`.
     /// <div class="warning">
     ///
-    /// Being these references, [`Self::advance()`] has to be called when done with the mutation
+    /// Being these references, [`MRBIterator::advance()`] has to be called when done with the mutation
     /// in order to move the iterator.
     /// </div>
     #[inline]
     fn get_workable_slice_exact<'a>(
         &mut self,
         count: usize,
-    ) -> Option<MutableSlice<'a, <Self as MRBIterator>::Item>> {
-        self.next_chunk_mut(count)
+    ) -> Option<SlicePairMut<'a, <Self as MRBIterator>::Item>> {
+        let ret = self.next_chunk_mut(count);
+        self.record(ret)
+    }
+
+    /// Calls `f` on a contiguous mutable slice of `count` items, returning its result.
+    ///
+    /// If the items wrap around the end of the buffer, they are copied into `scratch`, which is passed
+    /// to `f` and then written back. With double-mapped buffers, items are always contiguous, so
+    /// `scratch` is never used.
+    ///
+    /// Returns `None` if there are fewer than `count` available items.
+    /// <div class="warning">
+    ///
+    /// As with [`Self::get_workable_slice_exact()`], [`MRBIterator::advance()`] has to be called when done with
+    /// the mutation in order to move the iterator.
+    /// </div>
+    ///
+    /// # Panics
+    /// Panics if items wrap around and `scratch` is shorter than `count`.
+    fn with_contiguous_mut<R>(
+        &mut self,
+        count: usize,
+        scratch: &mut [<Self as MRBIterator>::Item],
+        f: impl FnOnce(&mut [<Self as MRBIterator>::Item]) -> R,
+    ) -> Option<R>
+    where
+        <Self as MRBIterator>::Item: Copy,
+    {
+        let mut items = self.get_workable_slice_exact(count)?;
+
+        if let Some(slice) = items.as_contiguous_mut() {
+            return Some(f(slice));
+        }
+
+        let scratch = &mut scratch[..count];
+        items.copy_to_slice(scratch);
+
+        let ret = f(scratch);
+        items.copy_from_slice(scratch);
+
+        Some(ret)
     }
 
-    /// Returns a tuple of mutable slice references, the sum of which with len equal to [`Self::available()`].
+    /// Returns a [`SlicePairMut`] with len equal to [`MRBIterator::available()`].
     /// <div class="warning">
     ///
-    /// Being these references, [`Self::advance()`] has to be called when done with the mutation
+    /// Being these references, [`MRBIterator::advance()`] has to be called when done with the mutation
     /// in order to move the iterator.
     /// </div>
     #[inline]
     fn get_workable_slice_avail<'a>(
         &mut self,
-    ) -> Option<MutableSlice<'a, <Self as MRBIterator>::Item>> {
+    ) -> Option<SlicePairMut<'a, <Self as MRBIterator>::Item>> {
         match self.available() {
-            0 => None,
+            0 => self.record(None),
             avail => self.get_workable_slice_exact(avail),
         }
     }
 
-    /// Returns a tuple of mutable slice references, the sum of which with len equal to the
+    /// Returns a [`SlicePairMut`] with len equal to the
     /// higher multiple of `rhs`.
     /// <div class="warning">
     ///
-    /// Being these references, [`Self::advance()`] has to be called when done with the mutation
+    /// Being these references, [`MRBIterator::advance()`] has to be called when done with the mutation
     /// in order to move the iterator.
     /// </div>
     #[inline]
     fn get_workable_slice_multiple_of<'a>(
         &mut self,
         rhs: usize,
-    ) -> Option<MutableSlice<'a, <Self as MRBIterator>::Item>> {
+    ) -> Option<SlicePairMut<'a, <Self as MRBIterator>::Item>> {
         let avail = self.available();
 
         unsafe {
             match avail.unchecked_sub(avail % rhs) {
-                0 => None,
+                0 => self.record(None),
                 avail => self.get_workable_slice_exact(avail),
             }
         }
@@ -151,28 +308,191 @@ pub trait MRBIterator: PrivateMRBIterator<Self::Item> {
 }
 
 pub(crate) trait PrivateMRBIterator<T> {
+    /// Role of the iterator.
+    const ROLE: Role;
+
     fn buffer(&self) -> &BufRef<'_, impl MutRB<Item = T>>;
     fn _available(&mut self) -> usize;
     fn cached_avail(&self) -> usize;
     fn set_cached_avail(&mut self, avail: usize);
     fn _index(&self) -> usize;
     fn set_local_index(&mut self, index: usize);
+    fn _position(&self) -> u64;
+    fn set_local_position(&mut self, pos: u64);
     /// Sets the global index of this iterator.
     fn set_atomic_index(&self, index: usize);
 
     /// Returns the global index of successor.
     fn succ_index(&self) -> usize;
 
+    /// Returns the global index of the iterator with the given role.
+    #[inline]
+    fn role_index(&self, role: Role) -> usize {
+        match role {
+            Role::Prod => self.buffer().prod_index(),
+            Role::Work => self.buffer().work_index(),
+            Role::Cons => self.buffer().cons_index(),
+        }
+    }
+
+    /// Returns the absolute position of the iterator with the given role, derived from the local
+    /// position and the distance between indices.
+    ///
+    /// The position of this iterator's own role refers to the global index, which may differ from the
+    /// local one when the iterator is detached. In that case, it is derived from another iterator
+    /// used as an anchor.
+    fn total_of(&self, role: Role) -> u64 {
+        let len = self.buffer().inner_len();
+        let dist = |from: usize, to: usize| ((to + len - from) % len) as u64;
+        let (idx, pos) = (self._index(), self._position());
+
+        match role.cmp(&Self::ROLE) {
+            Ordering::Greater => pos.wrapping_add(dist(idx, self.role_index(role))),
+            Ordering::Less => pos.wrapping_sub(dist(self.role_index(role), idx)),
+            Ordering::Equal => {
+                let own = self.role_index(role);
+
+                if Self::ROLE == Role::Prod {
+                    let anchor = self.role_index(Role::Cons);
+                    pos.wrapping_sub(dist(anchor, idx))
+                        .wrapping_add(dist(anchor, own))
+                } else {
+                    let anchor = self.role_index(Role::Prod);
+                    pos.wrapping_add(dist(idx, anchor))
+                        .wrapping_sub(dist(own, anchor))
+                }
+            }
+        }
+    }
+
+    /// Returns the number of items between the local index and the successor.
+    #[inline]
+    fn offset_from_succ(&self) -> usize {
+        let len = self.buffer().inner_len();
+        (self.succ_index() + len - self._index()) % len
+    }
+
+    /// Moves the local index back by `count`, wrapping if necessary.
+    #[inline]
+    unsafe fn go_back_local(&mut self, count: usize) {
+        let (idx, len) = (self._index(), self.buffer().inner_len());
+
+        self.set_local_index(match idx < count {
+            true => unsafe { len.unchecked_add(idx).unchecked_sub(count) },
+            false => unsafe { idx.unchecked_sub(count) },
+        });
+        self.set_local_position(self._position().wrapping_sub(count as u64));
+        self.set_cached_avail(unsafe { self.cached_avail().unchecked_add(count) });
+    }
+
+    /// Moves the local index, checking that it stays between the global index of the iterator
+    /// and its successor. Returns the new position.
+    ///
+    /// Only to be called on workers and consumers, which can reach their successor.
+    fn seek_local(&mut self, from: SeekFrom) -> Result<u64, SeekError> {
+        let start = self.total_of(Self::ROLE);
+        let len = self.buffer().inner_len();
+        let own = self.role_index(Self::ROLE);
+        let reach = (self.succ_index() + len - own) % len;
+        let end = start.wrapping_add(reach as u64);
+
+        let target = match from {
+            SeekFrom::Start(pos) => pos as i128,
+            SeekFrom::Current(offset) => self._position() as i128 + offset as i128,
+            SeekFrom::End(offset) => end as i128 + offset as i128,
+        };
+
+        if target < start as i128 {
+            return Err(SeekError::BehindIndex);
+        } else if target > end as i128 {
+            return Err(SeekError::BeyondSuccessor);
+        }
+
+        let (target, offset) = (target as u64, (target as u64 - start) as usize);
+
+        self.set_local_index((own + offset) % len);
+        self.set_local_position(target);
+        self.set_cached_avail((end - target) as usize);
+
+        Ok(target)
+    }
+
+    /// Releases `count` items starting from `index`, once the iterator is done with them.
+    /// Only the consumer owns the items it passes over, so this is a no-op for other iterators.
+    #[inline]
+    unsafe fn release(&self, _index: usize, _count: usize) {}
+
+    /// Releases the items written locally, i.e. between the global and the local index.
+    /// Only the producer writes items, so this is a no-op for other iterators.
+    #[inline]
+    unsafe fn release_local(&self) {}
+
+    /// Moves the local index to the location occupied by the successor.
+    fn rewind_local(&mut self) {
+        let offset = self.offset_from_succ();
+
+        unsafe { self.advance_local(offset) };
+        self.set_cached_avail(0);
+    }
+
+    /// Moves the local index back to the global one, discarding local changes.
+    #[inline]
+    fn rollback_local(&mut self) {
+        let pos = self.total_of(Self::ROLE);
+
+        self.set_local_index(self.role_index(Self::ROLE));
+        self.set_local_position(pos);
+        self.set_cached_avail(0);
+    }
+
+    /// Sets the global index of this iterator, updating the statistics of the buffer.
+    #[inline]
+    fn store_index(&self, index: usize) {
+        #[cfg(feature = "stats")]
+        {
+            let buf = self.buffer();
+            let len = buf.inner_len();
+            let stats = buf.stats();
+
+            stats.record_moved(
+                Self::ROLE,
+                (index + len - self.role_index(Self::ROLE)) % len,
+            );
+
+            if Self::ROLE == Role::Prod {
+                stats.record_occupancy((index + len - buf.cons_index()) % len);
+            }
+        }
+
+        self.set_atomic_index(index);
+    }
+
+    /// Synchronises the atomic index with the local one, releasing the items in between.
+    #[inline]
+    fn sync_atomic(&self) {
+        self.store_index(self._index());
+    }
+
     #[inline]
     unsafe fn _advance(&mut self, count: usize) {
+        unsafe {
+            self.release(self._index(), count);
+            self.advance_moved(count);
+        }
+    }
+
+    /// Same as [`Self::_advance`], but does not release the items, as these have been moved out.
+    #[inline]
+    unsafe fn advance_moved(&mut self, count: usize) {
         unsafe { self.advance_local(count) };
 
-        self.set_atomic_index(self._index());
+        self.store_index(self._index());
     }
 
     #[inline]
     unsafe fn advance_local(&mut self, count: usize) {
         self.set_local_index(unsafe { self._index().unchecked_add(count) });
+        self.set_local_position(self._position().wrapping_add(count as u64));
 
         if self._index() >= self.buffer().inner_len() {
             self.set_local_index(unsafe { self._index().unchecked_sub(self.buffer().inner_len()) });
@@ -187,21 +507,22 @@ pub(crate) trait PrivateMRBIterator<T> {
         self.cached_avail() >= count || self._available() >= count
     }
 
-    /// Returns Some(current element), if `check()` returns `true`, else None
+    /// Records a failed push, pop or work if `ret` is `None`, then returns `ret`.
+    ///
+    /// Only to be called by these operations, so that peeks and probes are not recorded.
     #[inline]
-    fn next(&mut self) -> Option<T> {
-        self.check(1).then(|| unsafe {
-            let ret = self.buffer().inner()._index(self._index()).take_inner();
-
-            self._advance(1);
+    fn record<R>(&self, ret: Option<R>) -> Option<R> {
+        #[cfg(feature = "stats")]
+        if ret.is_none() {
+            self.buffer().stats().record_failure(Self::ROLE);
+        }
 
-            ret
-        })
+        ret
     }
 
-    /// Returns Some(current element), if `check()` returns `true`, else None. The value is duplicated.
+    /// Returns Some(current element), if `check()` returns `true`, else None. The value is moved out.
     #[inline]
-    fn next_duplicate(&mut self) -> Option<T> {
+    fn next(&mut self) -> Option<T> {
         self.check(1).then(|| unsafe {
             let ret = self
                 .buffer()
@@ -209,7 +530,7 @@ pub(crate) trait PrivateMRBIterator<T> {
                 ._index(self._index())
                 .inner_duplicate();
 
-            self._advance(1);
+            self.advance_moved(1);
 
             ret
         })
@@ -233,6 +554,35 @@ pub(crate) trait PrivateMRBIterator<T> {
         }
     }
 
+    /// Returns Some(&element `offset` items ahead), if `check()` returns `true`, else None
+    #[inline]
+    fn next_ref_at<'a>(&mut self, offset: usize) -> Option<&'a T> {
+        let index = self.offset_index(offset);
+
+        unsafe {
+            self.check(offset.checked_add(1)?)
+                .then(|| self.buffer().inner()._index(index).inner_ref())
+        }
+    }
+
+    /// Returns Some(&mut element `offset` items ahead), if `check()` returns `true`, else None
+    #[inline]
+    fn next_ref_mut_at<'a>(&mut self, offset: usize) -> Option<&'a mut T> {
+        let index = self.offset_index(offset);
+
+        unsafe {
+            self.check(offset.checked_add(1)?)
+                .then(|| self.buffer().inner()._index(index).inner_ref_mut())
+        }
+    }
+
+    /// Returns the index `offset` items ahead of the local one, wrapping if necessary.
+    #[inline]
+    fn offset_index(&self, offset: usize) -> usize {
+        let len = self.buffer().inner_len();
+        (self._index() + offset % len) % len
+    }
+
     /// As next_ref_mut, but can be used for initialisation of inner MaybeUninit.
     #[inline]
     fn next_ref_mut_init(&mut self) -> Option<*mut T> {
@@ -240,91 +590,57 @@ pub(crate) trait PrivateMRBIterator<T> {
             .then(|| self.buffer().inner()._index(self._index()).as_mut_ptr())
     }
 
-    #[cfg(feature = "vmem")]
     #[inline]
-    fn next_chunk<'a>(&mut self, count: usize) -> Option<NonMutableSlice<'a, T>> {
-        self.check(count).then(|| unsafe {
-            transmute::<&[UnsafeSyncCell<T>], &[T]>(slice::from_raw_parts(
-                self.buffer().inner().as_ptr().add(self._index()),
-                count,
-            ))
-        })
+    fn next_chunk<'a>(&mut self, count: usize) -> Option<SlicePair<'a, T>> {
+        self.check(count)
+            .then(|| unsafe { self.chunk_at(self._index(), count) })
     }
-    #[cfg(feature = "vmem")]
+
+    /// Returns `count` items starting from `index`, wrapping if necessary.
+    ///
+    /// # Safety
+    /// Items within the range must be initialised.
     #[inline]
-    fn next_chunk_mut<'a>(&mut self, count: usize) -> Option<MutableSlice<'a, T>> {
-        self.check(count).then(|| unsafe {
-            transmute::<&mut [UnsafeSyncCell<T>], &mut [T]>(slice::from_raw_parts_mut(
-                self.buffer().inner_mut().as_mut_ptr().add(self._index()),
-                count,
-            ))
-        })
+    unsafe fn chunk_at<'a>(&self, index: usize, count: usize) -> SlicePair<'a, T> {
+        let (head, tail) = self.chunk_ranges(index, count);
+
+        unsafe {
+            let ptr = self.buffer().inner().as_ptr() as *const T;
+
+            SlicePair::new(
+                slice::from_raw_parts(ptr.add(index), head),
+                slice::from_raw_parts(ptr, tail),
+            )
+        }
     }
 
-    #[cfg(not(feature = "vmem"))]
     #[inline]
-    fn next_chunk<'a>(&mut self, count: usize) -> Option<NonMutableSlice<'a, T>> {
+    fn next_chunk_mut<'a>(&mut self, count: usize) -> Option<SlicePairMut<'a, T>> {
         self.check(count).then(|| {
-            let len = self.buffer().inner_len();
+            let index = self._index();
+            let (head, tail) = self.chunk_ranges(index, count);
 
             unsafe {
-                let ptr = self.buffer().inner().as_ptr();
-
-                if self._index() + count >= len {
-                    (
-                        transmute::<&[UnsafeSyncCell<T>], &[T]>(slice::from_raw_parts(
-                            ptr.add(self._index()),
-                            len.unchecked_sub(self._index()),
-                        )),
-                        transmute::<&[UnsafeSyncCell<T>], &[T]>(slice::from_raw_parts(
-                            ptr,
-                            self._index().unchecked_add(count).unchecked_sub(len),
-                        )),
-                    )
-                } else {
-                    (
-                        transmute::<&[UnsafeSyncCell<T>], &[T]>(slice::from_raw_parts(
-                            ptr.add(self._index()),
-                            count,
-                        )),
-                        &mut [] as &[T],
-                    )
-                }
+                let ptr = self.buffer().inner_mut().as_mut_ptr() as *mut T;
+
+                SlicePairMut::new(
+                    slice::from_raw_parts_mut(ptr.add(index), head),
+                    slice::from_raw_parts_mut(ptr, tail),
+                )
             }
         })
     }
 
-    #[cfg(not(feature = "vmem"))]
+    /// Returns the lengths of the two parts of a chunk of `count` items starting from `index`.
+    /// If the buffer is double mapped, the second one is always `0`.
     #[inline]
-    fn next_chunk_mut<'a>(&mut self, count: usize) -> Option<MutableSlice<'a, T>> {
-        self.check(count).then(|| {
-            let len = self.buffer().inner_len();
+    fn chunk_ranges(&self, index: usize, count: usize) -> (usize, usize) {
+        let len = self.buffer().inner_len();
 
-            unsafe {
-                let ptr = self.buffer().inner_mut().as_mut_ptr();
-
-                if self._index() + count >= len {
-                    (
-                        transmute::<&mut [UnsafeSyncCell<T>], &mut [T]>(slice::from_raw_parts_mut(
-                            ptr.add(self._index()),
-                            len.unchecked_sub(self._index()),
-                        )),
-                        transmute::<&mut [UnsafeSyncCell<T>], &mut [T]>(slice::from_raw_parts_mut(
-                            ptr,
-                            self._index().unchecked_add(count).unchecked_sub(len),
-                        )),
-                    )
-                } else {
-                    (
-                        transmute::<&mut [UnsafeSyncCell<T>], &mut [T]>(slice::from_raw_parts_mut(
-                            ptr.add(self._index()),
-                            count,
-                        )),
-                        &mut [] as &mut [T],
-                    )
-                }
-            }
-        })
+        match index + count > len && !self.buffer().inner().is_double_mapped() {
+            true => (len - index, index + count - len),
+            false => (count, 0),
+        }
     }
 }
 
@@ -345,6 +661,15 @@ pub(crate) mod iter_macros {
                 self.index = index;
             }
 
+            #[inline]
+            fn _position(&self) -> u64 {
+                self.pos
+            }
+            #[inline]
+            fn set_local_position(&mut self, pos: u64) {
+                self.pos = pos;
+            }
+
             #[inline]
             fn cached_avail(&self) -> usize {
                 self.cached_avail
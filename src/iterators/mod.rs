@@ -1,7 +1,8 @@
 //! Module containing sync and async iterators.
 
 pub mod async_iterators;
-mod iterator_trait;
+pub(crate) mod iterator_trait;
+mod slice_pair;
 pub(crate) mod sync_iterators;
 
 #[cfg(any(feature = "async", doc))]
@@ -11,13 +12,35 @@ pub use async_iterators::{
 };
 
 pub use sync_iterators::{
-    cons_iter::ConsIter, detached::Detached, prod_iter::ProdIter, work_iter::WorkIter,
+    bip_iter::{BipConsIter, BipGrant, BipProdIter},
+    cons_iter::ConsIter,
+    detached::{Detached, SeekError, SeekFrom},
+    dyn_entries::{DynConsumer, DynProducer},
+    frames::Frames,
+    framing::{Cobs, CobsDecoder, Delimited, FrameDecoder, FrameError, Slip, SlipDecoder},
+    messages::{MESSAGE_HEADER_LEN, Message, MessageConsumer, MessageError, MessageProducer},
+    prod_iter::{ProdIter, UninitSlots},
+    uninit_prod_iter::UninitProdIter,
+    work_iter::{Windows, WorkIter},
 };
 
+#[cfg_attr(doc, doc(cfg(all(unix, feature = "fd"))))]
+#[cfg(all(unix, feature = "fd"))]
+pub use sync_iterators::io::FdError;
+
+#[cfg_attr(
+    doc,
+    doc(cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd")))
+)]
+#[cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd"))]
+pub use sync_iterators::datagram::Datagram;
+
 use core::ptr;
+pub use iterator_trait::FollowerIter;
 pub use iterator_trait::MRBIterator;
-pub use iterator_trait::MutableSlice;
-pub use iterator_trait::NonMutableSlice;
+#[allow(deprecated)]
+pub use iterator_trait::{MutableSlice, NonMutableSlice};
+pub use slice_pair::{SlicePair, SlicePairMut};
 
 pub(crate) use iterator_trait::iter_macros::*;
 
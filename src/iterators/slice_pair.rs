@@ -0,0 +1,349 @@
+use core::iter::Chain;
+use core::ops::{Index, IndexMut};
+use core::slice;
+#[cfg(feature = "std")]
+use std::io::{IoSlice, IoSliceMut};
+
+/// Non-mutable view over a region of the buffer, made of two slices.
+///
+/// The region may wrap around the end of the buffer, in which case the second slice holds the items
+/// starting from its beginning. With double-mapped buffers (see `VmemStorage`), the second slice is always empty.
+///
+/// Items are indexed starting from the iterator which yielded the view, regardless of
+/// where the region wraps around.
+pub struct SlicePair<'a, T> {
+    head: &'a [T],
+    tail: &'a [T],
+}
+
+impl<T> Clone for SlicePair<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SlicePair<'_, T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for SlicePair<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SlicePair<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> SlicePair<'a, T> {
+    #[inline]
+    pub(crate) fn new(head: &'a [T], tail: &'a [T]) -> Self {
+        Self { head, tail }
+    }
+
+    /// Returns the number of items in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+
+    /// Returns `true` if the view contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the item at `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index < self.head.len() {
+            true => self.head.get(index),
+            false => self.tail.get(index - self.head.len()),
+        }
+    }
+
+    /// Returns an iterator over the items in the view.
+    #[inline]
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.head.iter().chain(self.tail)
+    }
+
+    /// Returns the two slices the view is made of. The second one is empty if the region
+    /// does not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.head, self.tail)
+    }
+
+    /// Returns the view as a single slice, if it does not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_contiguous(&self) -> Option<&'a [T]> {
+        self.tail.is_empty().then_some(self.head)
+    }
+
+    /// Divides the view into two at `mid`, the first containing the items in `[0, mid)`.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than [`Self::len`].
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (SlicePair<'a, T>, SlicePair<'a, T>) {
+        assert!(mid <= self.len(), "mid > len");
+
+        match mid <= self.head.len() {
+            true => {
+                let (l, r) = self.head.split_at(mid);
+                (Self::new(l, &[]), Self::new(r, self.tail))
+            }
+            false => {
+                let (l, r) = self.tail.split_at(mid - self.head.len());
+                (Self::new(self.head, l), Self::new(r, &[]))
+            }
+        }
+    }
+
+    /// Copies all the items in the view into `dst`.
+    ///
+    /// # Panics
+    /// Panics if the length of `dst` differs from [`Self::len`].
+    #[inline]
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            dst.len(),
+            self.len(),
+            "destination and view have different lengths"
+        );
+
+        let (dst_head, dst_tail) = dst.split_at_mut(self.head.len());
+        dst_head.copy_from_slice(self.head);
+        dst_tail.copy_from_slice(self.tail);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> SlicePair<'a, u8> {
+    /// Returns the view as two [`IoSlice`]s, to be used with vectored writes.
+    #[inline]
+    pub fn as_io_slices(&self) -> [IoSlice<'a>; 2] {
+        [IoSlice::new(self.head), IoSlice::new(self.tail)]
+    }
+}
+
+impl<T> Index<usize> for SlicePair<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, T> IntoIterator for SlicePair<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutable view over a region of the buffer, made of two slices.
+///
+/// The region may wrap around the end of the buffer, in which case the second slice holds the items
+/// starting from its beginning. With double-mapped buffers (see `VmemStorage`), the second slice is always empty.
+///
+/// Items are indexed starting from the iterator which yielded the view, regardless of
+/// where the region wraps around.
+pub struct SlicePairMut<'a, T> {
+    head: &'a mut [T],
+    tail: &'a mut [T],
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for SlicePairMut<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> SlicePairMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(head: &'a mut [T], tail: &'a mut [T]) -> Self {
+        Self { head, tail }
+    }
+
+    /// Returns the number of items in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+
+    /// Returns `true` if the view contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the item at `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index < self.head.len() {
+            true => self.head.get(index),
+            false => self.tail.get(index - self.head.len()),
+        }
+    }
+
+    /// Returns a mutable reference to the item at `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < self.head.len() {
+            true => self.head.get_mut(index),
+            false => self.tail.get_mut(index - self.head.len()),
+        }
+    }
+
+    /// Returns an iterator over the items in the view.
+    #[inline]
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        self.head.iter().chain(self.tail.iter())
+    }
+
+    /// Returns an iterator over mutable references to the items in the view.
+    #[inline]
+    pub fn iter_mut(&mut self) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        self.head.iter_mut().chain(self.tail.iter_mut())
+    }
+
+    /// Returns the two slices the view is made of. The second one is empty if the region
+    /// does not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        (self.head, self.tail)
+    }
+
+    /// Same as [`Self::as_slices`], but returns mutable slices.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self.head, self.tail)
+    }
+
+    /// Consumes the view, returning the two slices it is made of.
+    #[inline]
+    pub fn into_slices(self) -> (&'a mut [T], &'a mut [T]) {
+        (self.head, self.tail)
+    }
+
+    /// Returns the view as a single slice, if it does not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_contiguous(&self) -> Option<&[T]> {
+        self.tail.is_empty().then_some(&*self.head)
+    }
+
+    /// Returns the view as a single mutable slice, if it does not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_contiguous_mut(&mut self) -> Option<&mut [T]> {
+        self.tail.is_empty().then_some(&mut *self.head)
+    }
+
+    /// Divides the view into two at `mid`, the first containing the items in `[0, mid)`.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than [`Self::len`].
+    #[inline]
+    pub fn split_at(self, mid: usize) -> (SlicePairMut<'a, T>, SlicePairMut<'a, T>) {
+        assert!(mid <= self.len(), "mid > len");
+
+        match mid <= self.head.len() {
+            true => {
+                let (l, r) = self.head.split_at_mut(mid);
+                (Self::new(l, &mut []), Self::new(r, self.tail))
+            }
+            false => {
+                let (l, r) = self.tail.split_at_mut(mid - self.head.len());
+                (Self::new(self.head, l), Self::new(r, &mut []))
+            }
+        }
+    }
+
+    /// Copies all the items in `src` into the view.
+    ///
+    /// # Panics
+    /// Panics if the length of `src` differs from [`Self::len`].
+    #[inline]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            src.len(),
+            self.len(),
+            "source and view have different lengths"
+        );
+
+        let (src_head, src_tail) = src.split_at(self.head.len());
+        self.head.copy_from_slice(src_head);
+        self.tail.copy_from_slice(src_tail);
+    }
+
+    /// Copies all the items in the view into `dst`.
+    ///
+    /// # Panics
+    /// Panics if the length of `dst` differs from [`Self::len`].
+    #[inline]
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        self.as_pair().copy_to_slice(dst)
+    }
+
+    /// Returns a non-mutable view over the same items.
+    #[inline]
+    pub fn as_pair(&self) -> SlicePair<'_, T> {
+        SlicePair::new(self.head, self.tail)
+    }
+
+    /// Converts the view into a non-mutable one.
+    #[inline]
+    pub fn into_pair(self) -> SlicePair<'a, T> {
+        SlicePair::new(self.head, self.tail)
+    }
+}
+
+#[cfg(feature = "std")]
+impl SlicePairMut<'_, u8> {
+    /// Returns the view as two [`IoSliceMut`]s, to be used with vectored reads.
+    #[inline]
+    pub fn as_io_slices_mut(&mut self) -> [IoSliceMut<'_>; 2] {
+        [IoSliceMut::new(self.head), IoSliceMut::new(self.tail)]
+    }
+}
+
+impl<T> Index<usize> for SlicePairMut<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for SlicePairMut<'_, T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, T> IntoIterator for SlicePairMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.head.iter_mut().chain(self.tail.iter_mut())
+    }
+}
@@ -0,0 +1,224 @@
+use crate::BipMutRingBuf;
+use crate::ring_buffer::storage::Storage;
+use crate::ring_buffer::variants::ring_buffer_trait::StorageManager;
+use core::mem::MaybeUninit;
+use core::slice;
+
+#[doc = r##"
+Iterator used to push data into a [`BipMutRingBuf`].
+
+Items are written into contiguous regions, reserved with [`Self::reserve`].
+"##]
+pub struct BipProdIter<'buf, S: Storage> {
+    buffer: &'buf BipMutRingBuf<S>,
+}
+
+unsafe impl<S: Storage<Item = T>, T: Send> Send for BipProdIter<'_, S> {}
+
+impl<'buf, S: Storage<Item = T>, T> BipProdIter<'buf, S> {
+    pub(crate) fn new(buffer: &'buf BipMutRingBuf<S>) -> Self {
+        Self { buffer }
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.inner_len()
+    }
+
+    /// Returns the largest number of contiguous locations which can currently be reserved.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.buffer.max_reservable()
+    }
+
+    /// Reserves `count` contiguous locations, yielding a [`BipGrant`] through which they can be written.
+    ///
+    /// Written items become visible to the consumer only when committed.
+    ///
+    /// Returns `None` if `count` contiguous locations are not available.
+    pub fn reserve(&mut self, count: usize) -> Option<BipGrant<'_, 'buf, S>> {
+        let start = self.buffer.reserve_start(count)?;
+
+        Some(BipGrant {
+            iter: self,
+            start,
+            len: count,
+        })
+    }
+
+    /// Tries to push a new item, moving it.
+    ///
+    /// Returns:
+    /// * `Err(value)`, if the buffer is full;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        match self.reserve(1) {
+            Some(mut grant) => {
+                grant.as_uninit_mut()[0].write(value);
+                unsafe { grant.commit(1) };
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Tries to push a slice of items, copying them into a contiguous region.
+    ///
+    /// Returns:
+    /// * `None`, if there is no contiguous region large enough;
+    /// * `Some(())`, otherwise.
+    #[inline]
+    pub fn push_slice(&mut self, slice: &[T]) -> Option<()>
+    where
+        T: Copy,
+    {
+        self.reserve(slice.len())?.copy_from_slice(slice);
+        Some(())
+    }
+}
+
+#[doc = r##"
+Contiguous region reserved by a [`BipProdIter`].
+
+Locations are initially uninitialised: once written, they have to be committed to become visible to the consumer.
+Dropping a [`BipGrant`] without committing leaves the buffer untouched.
+"##]
+pub struct BipGrant<'a, 'buf, S: Storage> {
+    iter: &'a mut BipProdIter<'buf, S>,
+    start: usize,
+    len: usize,
+}
+
+impl<S: Storage<Item = T>, T> BipGrant<'_, '_, S> {
+    /// Returns the number of reserved locations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no location has been reserved.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the reserved region as a [`MaybeUninit`] slice.
+    #[inline]
+    pub fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.iter.buffer.ptr_at(self.start) as *mut MaybeUninit<T>,
+                self.len,
+            )
+        }
+    }
+
+    /// Makes the first `count` locations visible to the consumer, consuming the grant.
+    ///
+    /// # Safety
+    /// The first `count` locations must have been initialised, and `count` must not be greater than [`Self::len`].
+    #[inline]
+    pub unsafe fn commit(self, count: usize) {
+        self.iter.buffer.commit(self.start, count);
+    }
+
+    /// Copies `slice` into the reserved region and commits it.
+    ///
+    /// # Panics
+    /// Panics if the length of `slice` differs from [`Self::len`].
+    #[inline]
+    pub fn copy_from_slice(mut self, slice: &[T])
+    where
+        T: Copy,
+    {
+        let len = slice.len();
+
+        self.as_uninit_mut().copy_from_slice(unsafe {
+            slice::from_raw_parts(slice.as_ptr() as *const MaybeUninit<T>, len)
+        });
+        unsafe { self.commit(len) };
+    }
+
+    /// Fills the reserved region with items returned by `f` and commits it.
+    #[inline]
+    pub fn fill_with(mut self, mut f: impl FnMut() -> T) {
+        let len = self.len;
+
+        for slot in self.as_uninit_mut() {
+            slot.write(f());
+        }
+        unsafe { self.commit(len) };
+    }
+}
+
+#[doc = r##"
+Iterator used to pop data from a [`BipMutRingBuf`].
+
+Readable items are always returned as a single contiguous slice.
+"##]
+pub struct BipConsIter<'buf, S: Storage> {
+    buffer: &'buf BipMutRingBuf<S>,
+}
+
+unsafe impl<S: Storage<Item = T>, T: Send> Send for BipConsIter<'_, S> {}
+
+impl<'buf, S: Storage<Item = T>, T> BipConsIter<'buf, S> {
+    pub(crate) fn new(buffer: &'buf BipMutRingBuf<S>) -> Self {
+        Self { buffer }
+    }
+
+    /// Returns the length of the buffer.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffer.inner_len()
+    }
+
+    /// Returns the number of items which can currently be read contiguously.
+    #[inline]
+    pub fn available(&mut self) -> usize {
+        self.buffer.readable().1
+    }
+
+    /// Returns a slice containing all the items which can currently be read.
+    ///
+    /// Returns `None` if there is nothing to read.
+    /// <div class="warning">
+    ///
+    /// Being this a reference, [`Self::release`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    pub fn peek(&mut self) -> Option<&[T]> {
+        match self.buffer.readable() {
+            (_, 0) => None,
+            (start, len) => Some(unsafe { slice::from_raw_parts(self.buffer.ptr_at(start), len) }),
+        }
+    }
+
+    /// Releases the first `count` readable items, dropping them.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than [`Self::available`].
+    pub fn release(&mut self, count: usize) {
+        let (start, len) = self.buffer.readable();
+        assert!(count <= len, "cannot release more than the readable items");
+
+        unsafe { self.buffer.drop_range(start, count) };
+        self.buffer.set_read_index(start + count);
+    }
+
+    /// Tries to pop an element, moving it out of the buffer.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        match self.buffer.readable() {
+            (_, 0) => None,
+            (start, _) => {
+                let ret = unsafe { self.buffer.ptr_at(start).read() };
+                self.buffer.set_read_index(start + 1);
+                Some(ret)
+            }
+        }
+    }
+}
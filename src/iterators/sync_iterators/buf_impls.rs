@@ -0,0 +1,96 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::storage::MRBIndex;
+use crate::ring_buffer::variants::ring_buffer_trait::{MutRB, StorageManager};
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+// Note that `Buf::advance` shares its name with `MRBIterator::advance`: when both traits are in scope,
+// the method has to be called with the fully qualified syntax.
+
+impl<B: MutRB<Item = u8>, const W: bool> Buf for ConsIter<'_, B, W> {
+    /// Returns the number of available bytes.
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.available_uncached()
+    }
+
+    /// Returns the first of the two slices holding available bytes, i.e. the one which ends,
+    /// at most, at the end of the buffer.
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        let avail = self.available_uncached();
+
+        unsafe { self.chunk_at(self.index(), avail) }.as_slices().0
+    }
+
+    /// Fills `dst` with both the slices holding available bytes.
+    #[cfg(feature = "std")]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let avail = self.available_uncached();
+        let (head, tail) = unsafe { self.chunk_at(self.index(), avail) }.as_slices();
+
+        let mut count = 0;
+        for (s, d) in [head, tail]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .zip(dst.iter_mut())
+        {
+            *d = IoSlice::new(s);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// # Panics
+    /// If `cnt` > [`Self::remaining`].
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.available(),
+            "cannot advance past the available bytes"
+        );
+
+        unsafe { MRBIterator::advance(self, cnt) };
+    }
+}
+
+unsafe impl<B: MutRB<Item = u8>> BufMut for ProdIter<'_, B> {
+    /// Returns the number of free slots.
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.available_uncached()
+    }
+
+    /// # Panics
+    /// If `cnt` > [`Self::remaining_mut`].
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.available(),
+            "cannot advance past the free slots"
+        );
+
+        unsafe { MRBIterator::advance(self, cnt) };
+    }
+
+    /// Returns the first of the two slices made of free slots, i.e. the one which ends,
+    /// at most, at the end of the buffer.
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        let (index, avail) = (self.index(), self.available());
+        let head_len = match self.is_double_mapped() {
+            true => avail,
+            false => avail.min(self.buf_len() - index),
+        };
+
+        // Free slots may hold uninitialised bytes, so they are handed out straight from the raw pointer.
+        unsafe {
+            let ptr = self.buffer().inner()._index(index).as_mut_ptr();
+            UninitSlice::from_raw_parts_mut(ptr, head_len)
+        }
+    }
+}
@@ -1,19 +1,29 @@
+#[cfg(doc)]
+use crate::ConcurrentMutRingBuf;
 #[allow(unused_imports)]
 use crate::iterators::ProdIter;
-use crate::iterators::iterator_trait::{MRBIterator, NonMutableSlice, PrivateMRBIterator};
-use crate::iterators::{copy_from_slice_unchecked, private_impl};
+use crate::iterators::iterator_trait::{
+    FollowerIter, MRBIterator, PrivateMRBIterator, Role, debug_iter,
+};
+use crate::iterators::{Frames, SlicePair, copy_from_slice_unchecked, private_impl};
+use crate::ring_buffer::storage::MRBIndex;
 use crate::ring_buffer::variants::ring_buffer_trait::{ConcurrentRB, IterManager, MutRB};
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
+use core::fmt;
 
 #[doc = r##"
 Iterator used to pop data from the buffer.
 
 When working with types which implement both [`Copy`] and [`Clone`] traits, `copy` methods should be
 preferred over `clone` methods.
+
+Items are owned by the buffer until the consumer moves past them: [`Self::pop`] moves them out,
+while any other way of advancing the iterator (e.g. [`Self::advance`] after a `peek*` method) drops them.
 "##]
 pub struct ConsIter<'buf, B: MutRB, const W: bool> {
     index: usize,
     cached_avail: usize,
+    pos: u64,
     buffer: BufRef<'buf, B>,
 }
 
@@ -26,20 +36,11 @@ impl<B: MutRB + IterManager, const W: bool> Drop for ConsIter<'_, B, W> {
 }
 
 impl<B: MutRB<Item = T>, T, const W: bool> PrivateMRBIterator<T> for ConsIter<'_, B, W> {
+    const ROLE: Role = Role::Cons;
+
     #[inline]
     fn _available(&mut self) -> usize {
-        let succ_idx = self.succ_index();
-
-        unsafe {
-            self.cached_avail = match self.index <= succ_idx {
-                true => succ_idx.unchecked_sub(self.index),
-                false => self
-                    .buf_len()
-                    .unchecked_sub(self.index)
-                    .unchecked_add(succ_idx),
-            };
-        }
-
+        self.cached_avail = self.available_uncached();
         self.cached_avail
     }
 
@@ -48,6 +49,19 @@ impl<B: MutRB<Item = T>, T, const W: bool> PrivateMRBIterator<T> for ConsIter<'_
         self.buffer.set_cons_index(index);
     }
 
+    #[inline]
+    unsafe fn release(&self, index: usize, count: usize) {
+        unsafe { self.buffer.drop_range(index, count) };
+    }
+
+    #[inline]
+    fn sync_atomic(&self) {
+        let (atomic, len) = (self.buffer.cons_index(), self.buffer.inner_len());
+
+        unsafe { self.release(atomic, (self.index + len - atomic) % len) };
+        self.store_index(self.index);
+    }
+
     #[inline]
     fn succ_index(&self) -> usize {
         if W {
@@ -64,21 +78,53 @@ impl<B: MutRB<Item = T>, T, const W: bool> MRBIterator for ConsIter<'_, B, W> {
     type Item = T;
 }
 
+impl<B: MutRB<Item = T>, T, const W: bool> fmt::Debug for ConsIter<'_, B, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_iter(self, "ConsIter", f)
+    }
+}
+
+impl<B: MutRB<Item = T>, T, const W: bool> FollowerIter for ConsIter<'_, B, W> {}
+
 impl<'buf, B: MutRB<Item = T>, T, const W: bool> ConsIter<'buf, B, W> {
     pub(crate) fn new(value: BufRef<'buf, B>) -> Self {
         Self {
-            index: 0,
+            index: value.cons_index(),
+            pos: 0,
             buffer: value,
             cached_avail: 0,
         }
     }
 
-    /// Resets the index of the iterator. I.e., moves the iterator to the location occupied by its successor.
+    /// Returns a [`StatsHandle`](crate::StatsHandle), reading the statistics of the buffer from any thread.
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats_handle(&self) -> crate::StatsHandle<'buf, B> {
+        crate::StatsHandle::new(&self.buffer)
+    }
+
+    /// Same as [`MRBIterator::available`], but does not need `&mut self`, as the result is not cached.
+    #[inline]
+    pub(crate) fn available_uncached(&self) -> usize {
+        let succ_idx = self.succ_index();
+
+        unsafe {
+            match self.index <= succ_idx {
+                true => succ_idx.unchecked_sub(self.index),
+                false => self
+                    .buf_len()
+                    .unchecked_sub(self.index)
+                    .unchecked_add(succ_idx),
+            }
+        }
+    }
+
+    /// Resets the index of the iterator. I.e., moves the iterator to the location occupied by its successor,
+    /// dropping the items in between.
     #[inline]
     pub fn reset_index(&mut self) {
-        let new_idx = self.succ_index();
-        self.index = new_idx;
-        self.set_atomic_index(new_idx);
+        let count = self._available();
+        unsafe { self._advance(count) };
     }
 
     /// Returns a reference to an element.
@@ -92,52 +138,156 @@ impl<'buf, B: MutRB<Item = T>, T, const W: bool> ConsIter<'buf, B, W> {
         self.next_ref()
     }
 
-    /// Returns a tuple of slice references, the sum of which with len equal to `count`.
+    /// Returns a reference to the element `offset` items ahead of the current one, without
+    /// moving the iterator.
+    ///
+    /// Returns `None` if there are not more than `offset` available items.
+    /// <div class="warning">
+    ///
+    /// Being this a reference, [`Self::advance()`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    pub fn peek_at<'a>(&mut self, offset: usize) -> Option<&'a T> {
+        self.next_ref_at(offset)
+    }
+
+    /// Returns a view over all available items, which can be indexed regardless of where they wrap
+    /// around the end of the buffer.
+    /// <div class="warning">
+    ///
+    /// Being these references, [`Self::advance()`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    #[inline]
+    pub fn peek_window<'a>(&mut self) -> Option<SlicePair<'a, T>> {
+        self.peek_available()
+    }
+
+    /// Returns a [`SlicePair`] with len equal to `count`.
     /// <div class="warning">
     ///
     /// Being these references, [`Self::advance()`] has to be called when done with the data
     /// in order to move the iterator.
     /// </div>
     #[inline]
-    pub fn peek_slice<'a>(&mut self, count: usize) -> Option<NonMutableSlice<'a, T>> {
+    pub fn peek_slice<'a>(&mut self, count: usize) -> Option<SlicePair<'a, T>> {
         self.next_chunk(count)
     }
 
-    /// Returns a tuple of slice references, the sum of which with len equal to available data.
+    /// Returns a contiguous slice of `count` items.
+    ///
+    /// If the items wrap around the end of the buffer, they are copied into `scratch`, which is then
+    /// returned. With double-mapped buffers, items are always contiguous, so `scratch` is never used.
+    /// <div class="warning">
+    ///
+    /// As with [`Self::peek_slice()`], [`Self::advance()`] has to be called when done with the data
+    /// in order to move the iterator.
+    /// </div>
+    ///
+    /// # Panics
+    /// Panics if items wrap around and `scratch` is shorter than `count`.
+    pub fn peek_contiguous<'a>(&mut self, count: usize, scratch: &'a mut [T]) -> Option<&'a [T]>
+    where
+        T: Copy,
+    {
+        let items = self.peek_slice(count)?;
+
+        if let Some(slice) = items.as_contiguous() {
+            return Some(slice);
+        }
+
+        let scratch = &mut scratch[..count];
+        items.copy_to_slice(scratch);
+
+        Some(scratch)
+    }
+
+    /// Returns a [`SlicePair`] with len equal to available data.
     /// <div class="warning">
     ///
     /// Being these references, [`Self::advance()`] has to be called when done with the data
     /// in order to move the iterator.
     /// </div>
     #[inline]
-    pub fn peek_available<'a>(&mut self) -> Option<NonMutableSlice<'a, T>> {
+    pub fn peek_available<'a>(&mut self) -> Option<SlicePair<'a, T>> {
         match self.available() {
             0 => None,
             avail => self.peek_slice(avail),
         }
     }
 
-    /// Tries to pop an element, moving it.
+    /// Returns a [`Frames`], yielding references to frames of `N` items.
+    ///
+    /// # Panics
+    /// Panics if `N` is `0` or not lower than [`Self::buf_len`].
+    /// Unless the buffer is double mapped, also panics if neither the length of the buffer nor the index
+    /// of the iterator is a multiple of `N`.
+    pub fn peek_frames<const N: usize>(&mut self) -> Frames<'_, Self, N> {
+        Frames::new(self)
+    }
+
+    /// Tries to pop `N` elements at once, moving them out of the buffer.
+    ///
+    /// Returns `None` if there are fewer than `N` available items.
+    #[inline]
+    pub fn pop_array<const N: usize>(&mut self) -> Option<[T; N]> {
+        let ok = self.check(N).then_some(());
+
+        self.record(ok).map(|_| unsafe {
+            let len = self.buf_len();
+            let ret = core::array::from_fn(|i| {
+                self.buffer
+                    .inner()
+                    ._index((self.index + i) % len)
+                    .inner_duplicate()
+            });
+
+            self.advance_moved(N);
+
+            ret
+        })
+    }
+
+    /// Returns a [`SlicePair`] over the last `count` consumed items, the oldest first.
+    ///
+    /// Returns `None` if fewer than `count` items have been consumed, or if `count` is greater than
+    /// the history length set with `with_history` (e.g. [`ConcurrentMutRingBuf::with_history`]).
+    #[inline]
+    pub fn history<'a>(&self, count: usize) -> Option<SlicePair<'a, T>>
+    where
+        T: Copy,
+    {
+        if count > self.buffer.history_len() || count as u64 > self.pos {
+            return None;
+        }
+
+        let len = self.buf_len();
+        Some(unsafe { self.chunk_at((self.index + len - count) % len, count) })
+    }
+
+    /// Same as [`Self::pop`].
     /// # Safety
-    /// This method moves items, so locations from which they are moved out are left uninitialised.
-    /// These locations must be re-initialised used proper [`ProdIter`] methods (`*_init`) ones
+    /// This method is safe to use and is kept only for compatibility: the buffer keeps track of
+    /// moved-out locations, which can then be written with any [`ProdIter`] method.
+    #[deprecated(since = "1.0.0", note = "use `pop` instead")]
     #[inline]
     pub unsafe fn pop_move(&mut self) -> Option<T> {
-        self.next()
+        self.pop()
     }
 
-    /// Tries to pop an element, duplicating it.
-    /// # Safety
-    /// This method acts like `ptr::read`: it duplicates the item by making a bitwise copy, ignoring whether it is `Copy`/`Clone` or not.
-    /// So it is your responsibility to ensure that the data may indeed be duplicated.
+    /// Tries to pop an element, moving it out of the buffer.
     #[inline]
     pub fn pop(&mut self) -> Option<T> {
-        self.next_duplicate()
+        let ret = self.next();
+        self.record(ret)
     }
 
     #[inline]
     fn _extract_item(&mut self, dst: &mut T, f: fn(&T, &mut T)) -> Option<()> {
-        if let Some(v) = self.next_ref() {
+        let next = self.next_ref();
+
+        if let Some(v) = self.record(next) {
             f(v, dst);
 
             unsafe { self.advance(1) };
@@ -183,43 +333,18 @@ impl<'buf, B: MutRB<Item = T>, T, const W: bool> ConsIter<'buf, B, W> {
         self._extract_item(dst, f)
     }
 
-    #[cfg(feature = "vmem")]
-    #[inline]
-    fn _extract_slice(&mut self, dst: &mut [T], f: fn(&[T], &mut [T])) -> Option<()> {
-        let count = dst.len();
-
-        if let Some(binding) = self.next_chunk(count) {
-            unsafe {
-                f(binding, dst);
-                self.advance(count)
-            }
-            Some(())
-        } else {
-            None
-        }
-    }
-
-    #[cfg(not(feature = "vmem"))]
     #[inline]
     fn _extract_slice(&mut self, dst: &mut [T], f: fn(&[T], &mut [T])) -> Option<()> {
         let count = dst.len();
+        let chunk = self.next_chunk(count);
+        let (binding_h, binding_t) = self.record(chunk)?.as_slices();
 
-        if let Some((binding_h, binding_t)) = self.next_chunk_mut(count) {
-            let mid = binding_h.len();
-            if mid == dst.len() {
-                f(binding_h, dst);
-            } else {
-                unsafe {
-                    f(binding_h, dst.get_unchecked_mut(..mid));
-                    f(binding_t, dst.get_unchecked_mut(mid..));
-                }
-            }
+        let (dst_h, dst_t) = dst.split_at_mut(binding_h.len());
+        f(binding_h, dst_h);
+        f(binding_t, dst_t);
 
-            unsafe { self.advance(count) };
-            Some(())
-        } else {
-            None
-        }
+        unsafe { self.advance(count) };
+        Some(())
     }
 
     /// - Returns `Some(())`, filling `dst` slice with the next `dst.len()` values, if available.
@@ -269,7 +394,7 @@ mod test {
 
         const BUFFER_SIZE: usize = 100;
 
-        let buf = ConcurrentHeapRB::<u32>::default(BUFFER_SIZE + 1);
+        let buf = ConcurrentHeapRB::<u32>::new_zeroed(BUFFER_SIZE + 1);
         let (mut prod, mut cons) = buf.split();
 
         assert_eq!(cons.cached_avail, 0);
@@ -0,0 +1,308 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::sync_iterators::io::{FdError, iovec, retry};
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+use core::mem::{self, MaybeUninit};
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::{fmt, ptr};
+use libc::{c_int, c_uint, sockaddr_storage, socklen_t};
+
+/// Maximum number of datagrams moved by a single `recvmmsg`/`sendmmsg` call.
+const BATCH: usize = 64;
+
+/// A fixed-size packet slot, holding up to `N` bytes of payload and the address of the peer.
+///
+/// Meant to be used as the item of a ring buffer, e.g. [`DatagramRing`], which is filled
+/// with [`ProdIter::recv_datagrams`] and drained with [`ConsIter::send_datagrams`].
+/// Such a buffer is to be built with `new_zeroed`.
+#[cfg_attr(
+    doc,
+    doc(cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd")))
+)]
+#[derive(Clone, Copy)]
+pub struct Datagram<const N: usize> {
+    data: [u8; N],
+    len: usize,
+    addr: sockaddr_storage,
+    addr_len: socklen_t,
+    truncated: bool,
+}
+
+/// A heap-allocated ring buffer of [`Datagram`]s, usable in a concurrent environment.
+#[cfg_attr(
+    doc,
+    doc(cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        feature = "fd",
+        feature = "alloc"
+    )))
+)]
+#[cfg(feature = "alloc")]
+pub type DatagramRing<const N: usize> = crate::ConcurrentHeapRB<Datagram<N>>;
+
+impl<const N: usize> Default for Datagram<N> {
+    fn default() -> Self {
+        // All the fields are plain integers, so the zeroed value is valid.
+        unsafe { mem::zeroed() }
+    }
+}
+
+impl<const N: usize> fmt::Debug for Datagram<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Datagram")
+            .field("payload", &self.payload())
+            .field("addr", &self.addr())
+            .field("truncated", &self.truncated)
+            .finish()
+    }
+}
+
+impl<const N: usize> Datagram<N> {
+    /// Returns the payload of the datagram.
+    pub fn payload(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns a mutable reference to the payload of the datagram.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.data[..self.len]
+    }
+
+    /// Returns a mutable reference to the whole inner buffer, regardless of the length of the payload.
+    ///
+    /// To be used together with [`Self::set_len`].
+    pub fn buffer_mut(&mut self) -> &mut [u8; N] {
+        &mut self.data
+    }
+
+    /// Sets the length of the payload.
+    ///
+    /// # Panics
+    /// If `len` > `N`.
+    pub fn set_len(&mut self, len: usize) {
+        assert!(len <= N, "payload length exceeds the size of the slot");
+        self.len = len;
+    }
+
+    /// Copies `payload` into the datagram.
+    ///
+    /// # Panics
+    /// If `payload.len()` > `N`.
+    pub fn set_payload(&mut self, payload: &[u8]) {
+        self.set_len(payload.len());
+        self.data[..payload.len()].copy_from_slice(payload);
+    }
+
+    /// Returns `true` if the received datagram was larger than the slot, so its payload has been truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the address of the peer the datagram was received from, or will be sent to.
+    ///
+    /// Returns `None` if there is no address, or if it is neither IPv4 nor IPv6.
+    pub fn addr(&self) -> Option<SocketAddr> {
+        let storage = &self.addr as *const sockaddr_storage;
+
+        match self.addr.ss_family as c_int {
+            libc::AF_INET if self.addr_len as usize >= size_of::<libc::sockaddr_in>() => {
+                let sin = unsafe { &*(storage as *const libc::sockaddr_in) };
+
+                Some(SocketAddr::V4(SocketAddrV4::new(
+                    Ipv4Addr::from(sin.sin_addr.s_addr.to_ne_bytes()),
+                    u16::from_be(sin.sin_port),
+                )))
+            }
+            libc::AF_INET6 if self.addr_len as usize >= size_of::<libc::sockaddr_in6>() => {
+                let sin6 = unsafe { &*(storage as *const libc::sockaddr_in6) };
+
+                Some(SocketAddr::V6(SocketAddrV6::new(
+                    Ipv6Addr::from(sin6.sin6_addr.s6_addr),
+                    u16::from_be(sin6.sin6_port),
+                    sin6.sin6_flowinfo,
+                    sin6.sin6_scope_id,
+                )))
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets the address of the peer the datagram will be sent to.
+    ///
+    /// `None` is to be used with connected sockets.
+    pub fn set_addr(&mut self, addr: Option<SocketAddr>) {
+        self.addr = unsafe { mem::zeroed() };
+        let storage = &mut self.addr as *mut sockaddr_storage;
+
+        self.addr_len = match addr {
+            None => 0,
+            Some(SocketAddr::V4(a)) => {
+                let sin = unsafe { &mut *(storage as *mut libc::sockaddr_in) };
+                sin.sin_family = libc::AF_INET as libc::sa_family_t;
+                sin.sin_port = a.port().to_be();
+                sin.sin_addr.s_addr = u32::from_ne_bytes(a.ip().octets());
+
+                size_of::<libc::sockaddr_in>() as socklen_t
+            }
+            Some(SocketAddr::V6(a)) => {
+                let sin6 = unsafe { &mut *(storage as *mut libc::sockaddr_in6) };
+                sin6.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+                sin6.sin6_port = a.port().to_be();
+                sin6.sin6_flowinfo = a.flowinfo();
+                sin6.sin6_addr.s6_addr = a.ip().octets();
+                sin6.sin6_scope_id = a.scope_id();
+
+                size_of::<libc::sockaddr_in6>() as socklen_t
+            }
+        };
+    }
+}
+
+/// Builds the headers for `slots` in `msgs`, pointing each of them to its own entry of `iovs`.
+///
+/// With `recv`, the whole buffer and address storage of each slot are handed out;
+/// otherwise, only the payload and the address set by the user.
+fn fill_headers<const N: usize>(
+    slots: impl Iterator<Item = *mut Datagram<N>>,
+    iovs: &mut [MaybeUninit<libc::iovec>; BATCH],
+    msgs: &mut [MaybeUninit<libc::mmsghdr>; BATCH],
+    recv: bool,
+) -> usize {
+    let mut count = 0;
+
+    for ((slot, iov), msg) in slots.zip(iovs.iter_mut()).zip(msgs.iter_mut()) {
+        let (data, addr) = unsafe { (&raw mut (*slot).data, &raw mut (*slot).addr) };
+
+        let (len, name, name_len) = match (recv, unsafe { (*slot).addr_len }) {
+            (true, _) => (N, addr, size_of::<sockaddr_storage>() as _),
+            (false, 0) => (unsafe { (*slot).len }, ptr::null_mut(), 0),
+            (false, l) => (unsafe { (*slot).len }, addr, l),
+        };
+
+        let iov = iov.write(iovec(data as *const u8, len));
+
+        // `msghdr` may have private padding fields, so it is zeroed first.
+        let mut hdr: libc::msghdr = unsafe { mem::zeroed() };
+        hdr.msg_name = name as *mut _;
+        hdr.msg_namelen = name_len;
+        hdr.msg_iov = iov;
+        hdr.msg_iovlen = 1;
+
+        msg.write(libc::mmsghdr {
+            msg_hdr: hdr,
+            msg_len: 0,
+        });
+        count += 1;
+    }
+
+    count
+}
+
+// Free slots are handed out as `&mut Datagram`, even though the buffer only keeps the items between
+// the consumer and the producer alive, and regards the other locations as uninitialised.
+// This holds only because of what `Datagram` is, not because of the storage:
+// * it is plain data (integers, bytes and a flag), so a zeroed location is a valid `Datagram`,
+//   and buffers built with `new_zeroed` (as `DatagramRing` is meant to be) start fully valid;
+// * it is `Copy` and has no drop glue, so neither popping a datagram nor dropping it
+//   de-initialises its location, which keeps holding the last `Datagram` written to it.
+
+impl<B: MutRB<Item = Datagram<N>>, const N: usize> ProdIter<'_, B> {
+    /// Receives at most `max` datagrams from the socket `fd` straight into the free slots of the buffer,
+    /// advancing the iterator by the number of datagrams received, which is returned.
+    ///
+    /// Up to 64 datagrams are received with a single `recvmmsg` call, which returns as soon as at least
+    /// one datagram has been received (`MSG_WAITFORONE`).
+    /// Each slot records the length of the payload, the source address and whether the datagram has
+    /// been truncated.
+    ///
+    /// Returns:
+    /// * `Ok(0)`, if there are no free slots;
+    /// * `Err(FdError::WouldBlock)`, if `fd` is non-blocking and there is nothing to receive;
+    /// * `Err(FdError::Os(errno))`, if the call fails.
+    ///
+    /// Calls interrupted by a signal are retried.
+    #[cfg_attr(
+        doc,
+        doc(cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd")))
+    )]
+    pub fn recv_datagrams(&mut self, fd: c_int, max: usize) -> Result<usize, FdError> {
+        let count = self.available().min(max).min(BATCH);
+
+        let Some(mut slots) = self.next_chunk_mut(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+
+        let mut iovs = [const { MaybeUninit::uninit() }; BATCH];
+        let mut msgs = [const { MaybeUninit::uninit() }; BATCH];
+        let len = fill_headers::<N>(
+            slots.iter_mut().map(|s| s as *mut _),
+            &mut iovs,
+            &mut msgs,
+            true,
+        );
+        let msgs = msgs.as_mut_ptr() as *mut libc::mmsghdr;
+
+        let received = retry(|| unsafe {
+            libc::recvmmsg(
+                fd,
+                msgs,
+                len as c_uint,
+                libc::MSG_WAITFORONE as _,
+                ptr::null_mut(),
+            ) as _
+        })?;
+
+        for (i, slot) in slots.iter_mut().take(received).enumerate() {
+            let msg = unsafe { &*msgs.add(i) };
+
+            slot.len = (msg.msg_len as usize).min(N);
+            slot.addr_len = msg.msg_hdr.msg_namelen;
+            slot.truncated = msg.msg_hdr.msg_flags & libc::MSG_TRUNC != 0;
+        }
+
+        unsafe { self.advance(received) };
+        Ok(received)
+    }
+}
+
+impl<B: MutRB<Item = Datagram<N>>, const N: usize, const W: bool> ConsIter<'_, B, W> {
+    /// Sends at most `max` available datagrams through the socket `fd` straight from the buffer,
+    /// advancing the iterator by the number of datagrams sent, which is returned.
+    ///
+    /// Up to 64 datagrams are sent with a single `sendmmsg` call, each one to the address held by its slot
+    /// (or to the connected peer, if there is none).
+    ///
+    /// Returns:
+    /// * `Ok(0)`, if there are no available datagrams;
+    /// * `Err(FdError::WouldBlock)`, if `fd` is non-blocking and not ready to be written;
+    /// * `Err(FdError::Os(errno))`, if the call fails before sending the first datagram.
+    ///
+    /// Calls interrupted by a signal are retried.
+    #[cfg_attr(
+        doc,
+        doc(cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd")))
+    )]
+    pub fn send_datagrams(&mut self, fd: c_int, max: usize) -> Result<usize, FdError> {
+        let count = self.available().min(max).min(BATCH);
+
+        let Some(slots) = self.next_chunk(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+
+        let mut iovs = [const { MaybeUninit::uninit() }; BATCH];
+        let mut msgs = [const { MaybeUninit::uninit() }; BATCH];
+        // Slots are only read by `sendmmsg`.
+        let len = fill_headers::<N>(
+            slots.iter().map(|s| s as *const _ as *mut _),
+            &mut iovs,
+            &mut msgs,
+            false,
+        );
+        let msgs = msgs.as_mut_ptr() as *mut libc::mmsghdr;
+
+        let sent = retry(|| unsafe { libc::sendmmsg(fd, msgs, len as c_uint, 0) as _ })?;
+
+        unsafe { self.advance(sent) };
+        Ok(sent)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, Detached, ProdIter, WorkIter};
+use crate::ring_buffer::variants::ring_buffer_trait::{IterManager, MutRB, StorageManager};
+use defmt::{Format, Formatter};
+
+/// Same as [`crate::iterators::iterator_trait::debug_iter`], for `defmt`.
+fn format_iter<T, I: PrivateMRBIterator<T>>(iter: &I, name: &str, f: Formatter<'_>) {
+    let buf = iter.buffer();
+
+    defmt::write!(
+        f,
+        "{=str} {{ role: {}, index: {=usize}, position: {=u64}, cached_avail: {=usize}, buf_len: {=usize}, alive_iters: {=u8} }}",
+        name,
+        I::ROLE,
+        iter._index(),
+        iter._position(),
+        iter.cached_avail(),
+        buf.inner_len(),
+        buf.alive_iters()
+    );
+}
+
+impl<B: MutRB<Item = T>, T> Format for ProdIter<'_, B> {
+    fn format(&self, f: Formatter<'_>) {
+        format_iter(self, "ProdIter", f);
+    }
+}
+
+impl<B: MutRB<Item = T>, T> Format for WorkIter<'_, B> {
+    fn format(&self, f: Formatter<'_>) {
+        format_iter(self, "WorkIter", f);
+    }
+}
+
+impl<B: MutRB<Item = T>, T, const W: bool> Format for ConsIter<'_, B, W> {
+    fn format(&self, f: Formatter<'_>) {
+        format_iter(self, "ConsIter", f);
+    }
+}
+
+impl<I: MRBIterator + Format> Format for Detached<I> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(
+            f,
+            "Detached {{ inner: {}, atomic_index: {=usize} }}",
+            self.inner,
+            self.inner.role_index(I::ROLE)
+        );
+    }
+}
@@ -1,8 +1,51 @@
 #[allow(unused_imports)]
 use crate::iterators::WorkIter;
-use crate::iterators::iterator_trait::{MRBIterator, MutableSlice};
+use crate::iterators::iterator_trait::{FollowerIter, MRBIterator, PrivateMRBIterator};
+use crate::iterators::sync_iterators::prod_iter::{clone_into, copy_into};
 use crate::iterators::util_macros::delegate;
 use crate::iterators::util_macros::muncher;
+use crate::iterators::{ConsIter, ProdIter, SlicePair, SlicePairMut};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+use core::fmt::{self, Display, Formatter};
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+/// Location to seek to, as accepted by [`Detached::seek`].
+///
+/// Positions are absolute, as returned by [`MRBIterator::position`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeekFrom {
+    /// Seeks to the given absolute position.
+    Start(u64),
+    /// Seeks relatively to the current position of the iterator.
+    Current(i64),
+    /// Seeks relatively to the position of the successor.
+    End(i64),
+}
+
+/// Error returned by [`Detached::seek`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeekError {
+    /// The requested location is behind the global index of the iterator.
+    BehindIndex,
+    /// The requested location is beyond the successor.
+    BeyondSuccessor,
+}
+
+impl Display for SeekError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SeekError::BehindIndex => {
+                f.write_str("cannot seek behind the global index of the iterator")
+            }
+            SeekError::BeyondSuccessor => {
+                f.write_str("cannot seek beyond the successor of the iterator")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SeekError {}
 
 #[doc = r##"
 Detached iterator: does not update the atomic index when advancing.
@@ -25,13 +68,37 @@ As [`WorkIter`], this iterator returns mutable references to data stored within
 Thus, as stated in the docs written for the former, [`Self::advance`] has to be called when done with the mutation
 in order to move the iterator.
 </div>
+
+# Detached consumer
+A detached [`ConsIter`] can peek ahead and then either [`commit`](Self::commit) the items it passed over,
+which are then dropped, or [`roll back`](Self::rollback) to where it was when last synced.
+
+# Detached producer
+A detached [`ProdIter`] writes items locally, as in a transaction: written items become visible to the
+next iterator only when [`committed`](Self::commit), while [`Self::abort`] drops them.
+Dropping the detached producer aborts the pending transaction, as well.
 "##]
 pub struct Detached<I: MRBIterator> {
-    inner: I,
+    pub(crate) inner: I,
 }
 
 unsafe impl<I: MRBIterator> Send for Detached<I> {}
 
+impl<I: MRBIterator + fmt::Debug> fmt::Debug for Detached<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Detached")
+            .field("inner", &self.inner)
+            .field("atomic_index", &self.inner.role_index(I::ROLE))
+            .finish()
+    }
+}
+
+impl<I: MRBIterator> Drop for Detached<I> {
+    fn drop(&mut self) {
+        unsafe { self.inner.release_local() };
+    }
+}
+
 impl<T, I: MRBIterator<Item = T>> Detached<I> {
     /// Creates a [`Self`] from an iterator.
     #[inline]
@@ -43,7 +110,9 @@ impl<T, I: MRBIterator<Item = T>> Detached<I> {
     #[inline]
     pub fn attach(self) -> I {
         self.sync_index();
-        self.inner
+
+        let this = ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.inner) }
     }
 
     fn inner(&self) -> &I {
@@ -62,17 +131,19 @@ impl<T, I: MRBIterator<Item = T>> Detached<I> {
     ///
     /// # Safety
     /// Index must always be between consumer and producer.
+    /// [`Self::seek`] can be used to move the iterator safely.
     #[inline]
     pub unsafe fn set_index(&mut self, index: usize) {
-        self.inner.set_local_index(index);
-    }
+        let (succ, len) = (self.inner.succ_index(), self.inner.buf_len());
+        let dist = |from: usize| ((succ + len - from) % len) as u64;
 
-    /// Resets the *local* index of the iterator. I.e., moves the iterator to the location occupied by its successor.
-    /// To sync the atomic index, use [`Self::sync_index`].
-    #[inline]
-    pub fn reset_index(&mut self) {
-        let new_idx = self.inner.succ_index();
-        self.inner.set_local_index(new_idx);
+        let pos = self
+            .inner
+            ._position()
+            .wrapping_add(dist(self.inner.index()))
+            .wrapping_sub(dist(index));
+        self.inner.set_local_position(pos);
+        self.inner.set_local_index(index);
     }
 
     /// Advances the iterator as in [`MRBIterator::advance()`], but does not modify the atomic counter,
@@ -89,32 +160,239 @@ impl<T, I: MRBIterator<Item = T>> Detached<I> {
     ///
     /// # Safety
     /// Index must always be between consumer and producer.
+    /// [`Self::seek`] can be used to move the iterator safely.
+    #[inline]
     pub unsafe fn go_back(&mut self, count: usize) {
-        let idx = self.inner.index();
-
-        self.inner.set_local_index(match idx < count {
-            true => unsafe { self.inner.buf_len().unchecked_sub(count).unchecked_sub(idx) },
-            false => unsafe { idx.unchecked_sub(count) },
-        });
+        unsafe { self.inner.go_back_local(count) };
+    }
 
-        let cached_avail = self.inner.cached_avail();
-        self.inner
-            .set_cached_avail(unsafe { cached_avail.unchecked_add(count) });
+    /// Returns the number of items between the *local* index and the successor.
+    #[inline]
+    pub fn offset_from_successor(&self) -> usize {
+        self.inner.offset_from_succ()
     }
 
     delegate!(MRBIterator (inline), pub fn prod_index(&self) -> usize);
     delegate!(MRBIterator (inline), pub fn work_index(&self) -> usize);
     delegate!(MRBIterator (inline), pub fn cons_index(&self) -> usize);
 
-    delegate!(MRBIterator (inline), pub fn get_workable(&(mut) self) -> Option<&'_ mut T>);
-    delegate!(MRBIterator (inline), pub fn get_workable_slice_exact(&(mut) self, count: usize) -> Option<MutableSlice<'_, T>>);
-    delegate!(MRBIterator (inline), pub fn get_workable_slice_avail(&(mut) self) -> Option<MutableSlice<'_, T>>);
-    delegate!(MRBIterator (inline), pub fn get_workable_slice_multiple_of(&(mut) self, rhs: usize) -> Option<MutableSlice<'_, T>>);
+    delegate!(MRBIterator (inline), pub fn position(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_pushed(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_worked(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_popped(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn buffer_state(&self) -> crate::BufferState);
+    #[cfg(feature = "stats")]
+    delegate!(MRBIterator (inline), pub fn stats(&self) -> crate::StatsSnapshot);
 
     /// Synchronises the underlying atomic index with the local index. I.e. let the consumer iterator
     /// advance.
+    ///
+    /// When detaching a [`ConsIter`], the items between the two indices are dropped.
     #[inline]
     pub fn sync_index(&self) {
-        self.inner.set_atomic_index(self.inner.index());
+        self.inner.sync_atomic();
+    }
+
+    /// Same as [`Self::sync_index`].
+    #[inline]
+    pub fn commit(&self) {
+        self.sync_index();
+    }
+}
+
+impl<T, I: FollowerIter<Item = T>> Detached<I> {
+    /// Resets the *local* index of the iterator. I.e., moves the iterator to the location occupied by its successor.
+    /// To sync the atomic index, use [`Self::sync_index`].
+    #[inline]
+    pub fn reset_index(&mut self) {
+        self.inner.rewind_local();
+    }
+
+    /// Moves the *local* index of the iterator, as described by `pos`. Returns the new position.
+    ///
+    /// The iterator can be moved anywhere between its global index (i.e. where it was when last synced)
+    /// and its successor. If the requested location lies outside this range, the iterator is not moved
+    /// and an error is returned.
+    ///
+    /// To sync the atomic index, use [`Self::sync_index`].
+    pub fn seek(&mut self, pos: SeekFrom) -> Result<u64, SeekError> {
+        self.inner.seek_local(pos)
+    }
+
+    /// Same as [`Self::reset_index`], but returns the new position.
+    #[inline]
+    pub fn rewind_to_successor(&mut self) -> u64 {
+        self.reset_index();
+        self.inner._position()
+    }
+
+    /// Moves the *local* index back to the global one, i.e. where the iterator was when last synced.
+    #[inline]
+    pub fn rollback(&mut self) {
+        self.inner.rollback_local();
+    }
+
+    delegate!(FollowerIter (inline), pub fn get_workable(&(mut) self) -> Option<&'_ mut T>);
+    delegate!(FollowerIter (inline), pub fn get_workable_at(&(mut) self, offset: usize) -> Option<&'_ mut T>);
+    delegate!(FollowerIter (inline), pub fn get_workable_window(&(mut) self) -> Option<SlicePairMut<'_, T>>);
+    delegate!(FollowerIter (inline), pub fn get_workable_slice_exact(&(mut) self, count: usize) -> Option<SlicePairMut<'_, T>>);
+    delegate!(FollowerIter (inline), pub fn get_workable_slice_avail(&(mut) self) -> Option<SlicePairMut<'_, T>>);
+    delegate!(FollowerIter (inline), pub fn get_workable_slice_multiple_of(&(mut) self, rhs: usize) -> Option<SlicePairMut<'_, T>>);
+}
+
+impl<'buf, B: MutRB<Item = T>, T, const W: bool> Detached<ConsIter<'buf, B, W>> {
+    delegate!(ConsIter (inline), pub fn peek_ref(&(mut) self) -> Option<&'_ T>);
+    delegate!(ConsIter (inline), pub fn peek_at(&(mut) self, offset: usize) -> Option<&'_ T>);
+    delegate!(ConsIter (inline), pub fn peek_window(&(mut) self) -> Option<SlicePair<'_, T>>);
+    delegate!(ConsIter (inline), pub fn peek_slice(&(mut) self, count: usize) -> Option<SlicePair<'_, T>>);
+    delegate!(ConsIter (inline), pub fn peek_available(&(mut) self) -> Option<SlicePair<'_, T>>);
+
+    /// Returns the number of items the iterator has moved past since the last commit.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        let (atomic, len) = (self.inner.cons_index(), self.inner.buf_len());
+        (self.inner.index() + len - atomic) % len
+    }
+
+    /// Commits the pop of the first `count` items the iterator has moved past since the last commit,
+    /// dropping them. The *local* index does not move, so the remaining items stay pending, and can
+    /// still be committed or rolled back.
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than [`Self::pending`].
+    pub fn commit_partial(&mut self, count: usize) {
+        assert!(
+            count <= self.pending(),
+            "count must not exceed the number of pending items"
+        );
+
+        let (atomic, len) = (self.inner.cons_index(), self.inner.buf_len());
+
+        unsafe { self.inner.release(atomic, count) };
+        self.inner.store_index((atomic + count) % len);
+    }
+}
+
+impl<'buf, B: MutRB<Item = T>, T> Detached<ProdIter<'buf, B>> {
+    /// Writes a new item locally, as in [`ProdIter::push`].
+    /// The item becomes visible to the next iterator once [`committed`](Self::commit).
+    ///
+    /// Returns:
+    /// * `Err(value)`, if the buffer is full;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        self.inner.write_next(value)?;
+
+        unsafe { self.inner.advance_local(1) };
+        Ok(())
+    }
+
+    /// Writes a slice of items locally, as in [`ProdIter::push_slice`].
+    /// Items become visible to the next iterator once [`committed`](Self::commit).
+    ///
+    /// Returns:
+    /// * `None`, if the buffer is full;
+    /// * `Some(())`, otherwise.
+    #[inline]
+    pub fn push_slice(&mut self, slice: &[T]) -> Option<()>
+    where
+        T: Copy,
+    {
+        self.inner.write_slice(slice, copy_into)?;
+
+        unsafe { self.inner.advance_local(slice.len()) };
+        Some(())
+    }
+
+    /// Writes a slice of items locally, as in [`ProdIter::push_slice_clone`].
+    /// Items become visible to the next iterator once [`committed`](Self::commit).
+    ///
+    /// Returns:
+    /// * `None`, if the buffer is full;
+    /// * `Some(())`, otherwise.
+    #[inline]
+    pub fn push_slice_clone(&mut self, slice: &[T]) -> Option<()>
+    where
+        T: Clone,
+    {
+        self.inner.write_slice(slice, clone_into)?;
+
+        unsafe { self.inner.advance_local(slice.len()) };
+        Some(())
+    }
+
+    /// Returns the number of items written since the last commit.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        let (atomic, len) = (self.inner.prod_index(), self.inner.buf_len());
+        (self.inner.index() + len - atomic) % len
+    }
+
+    /// Drops the items written since the last commit, moving the iterator back to the global index.
+    #[inline]
+    pub fn abort(&mut self) {
+        unsafe { self.inner.release_local() };
+        self.inner.rollback_local();
+    }
+}
+
+impl<T, I: FollowerIter<Item = T>> Detached<I> {
+    /// Searches the available items for the first one satisfying `pred`, wrapping if necessary.
+    ///
+    /// Returns the offset of the item from the *local* index, without moving the iterator.
+    pub fn find(&mut self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.available_window()?.iter().position(pred)
+    }
+
+    /// Returns a view over the available items, i.e. those between the *local* index and the successor.
+    #[inline]
+    fn available_window(&mut self) -> Option<SlicePair<'_, T>> {
+        let avail = self.inner.available();
+        self.inner.next_chunk(avail)
+    }
+
+    /// Searches the available items for the first one equal to `value`, wrapping if necessary.
+    ///
+    /// Returns the offset of the item from the *local* index, without moving the iterator.
+    #[inline]
+    pub fn position_of(&mut self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.find(|x| x == value)
+    }
+
+    /// Advances the *local* index up to the first item satisfying `pred`, returning the number of skipped items.
+    ///
+    /// If no item satisfies `pred`, the iterator is moved to its successor and `None` is returned.
+    /// To sync the atomic index, use [`Self::sync_index`].
+    pub fn scan_until(&mut self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        let avail = self.inner.available();
+        let ret = self.find(pred);
+
+        unsafe { self.inner.advance_local(ret.unwrap_or(avail)) };
+        ret
+    }
+}
+
+impl<I: FollowerIter<Item = u8>> Detached<I> {
+    /// Searches the available bytes for the first occurrence of `needle`, wrapping if necessary.
+    ///
+    /// Returns the offset of the first byte of the occurrence from the *local* index, without moving the iterator.
+    /// An empty `needle` is found at offset `0`.
+    pub fn find_subslice(&mut self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        let items = self.available_window()?;
+
+        (0..(items.len() + 1).checked_sub(needle.len())?).find(|&start| {
+            needle
+                .iter()
+                .enumerate()
+                .all(|(i, b)| items[start + i] == *b)
+        })
     }
 }
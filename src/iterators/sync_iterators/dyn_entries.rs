@@ -0,0 +1,244 @@
+use crate::Storage;
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::variants::ring_buffer_trait::{MutRB, StorageManager};
+use core::marker::PhantomData;
+use core::{mem, ptr};
+
+/// Total length written in place of a real one, to tell the consumer to skip to the end of the buffer.
+const SKIP_MARKER: usize = usize::MAX;
+
+/// Function turning a pointer to a value into a pointer to `D`, i.e. the coercion passed to
+/// [`DynProducer::push`], called through a signature which does not depend on the type of the value.
+type Coerce<D> = fn(*mut u8) -> *mut D;
+
+/// Returns the length of the header preceding each entry: the total length of the entry,
+/// the offset and the alignment of the value, and the coercion of the value.
+#[inline]
+const fn header_len<D: ?Sized>() -> usize {
+    3 * size_of::<usize>() + size_of::<Coerce<D>>()
+}
+
+/// Returns the offset of a value aligned to `align` within the entry starting at `entry`.
+#[inline]
+fn value_offset<D: ?Sized>(entry: *mut u8, align: usize) -> usize {
+    header_len::<D>() + entry.wrapping_add(header_len::<D>()).align_offset(align)
+}
+
+#[doc = r##"
+Writes values of different types, all viewed as `D` (e.g. `dyn Trait`), inline into a buffer of bytes.
+
+This struct can only be created by calling [`ProdIter::dyn_producer`], which is unsafe, as entries are
+to be read only by a [`DynConsumer`] with the same `D`.
+
+Each entry is made of a header, holding its length, the offset and alignment of the value and the function
+coercing it to `D`, the padding needed to align the value, and the value itself.
+As no absolute pointer is stored, a buffer can be moved while holding entries: entries leave room for the
+largest padding, so that values can be realigned within them when popped.
+Entries never straddle the end of the buffer, unless it is double mapped: when an entry does not fit
+before the end, the remaining slots are skipped.
+
+Values are dropped in place by [`DynConsumer::pop_with`]. Values which are never popped are leaked.
+"##]
+pub struct DynProducer<'a, 'buf, B: MutRB, D: ?Sized> {
+    iter: &'a mut ProdIter<'buf, B>,
+    phantom: PhantomData<*mut D>,
+}
+
+impl<'a, 'buf, B: MutRB<Item = u8>, D: ?Sized> DynProducer<'a, 'buf, B, D> {
+    pub(crate) fn new(iter: &'a mut ProdIter<'buf, B>) -> Self {
+        Self {
+            iter,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn base_ptr(&self) -> *mut u8 {
+        self.iter.buffer().inner_mut().as_mut_ptr() as *mut u8
+    }
+
+    /// Returns the length of an entry holding a `T`, which fits the value wherever the entry is.
+    #[inline]
+    const fn entry_len<T>() -> usize {
+        header_len::<D>() + align_of::<T>() - 1 + size_of::<T>()
+    }
+
+    /// Tries to push `value`, which is viewed as `D` through `coerce`, usually `|v| v`.
+    ///
+    /// The whole entry is zeroed before the value is written.
+    ///
+    /// Returns:
+    /// * `Err(value)`, if there are not enough free slots for the entry;
+    /// * `Ok(())`, otherwise.
+    ///
+    /// # Panics
+    /// If `coerce` does not return a reference to `value` itself.
+    pub fn push<T: Send + 'static>(
+        &mut self,
+        value: T,
+        coerce: fn(&mut T) -> &mut D,
+    ) -> Result<(), T> {
+        let (len, mut index) = (self.iter.buf_len(), self.iter.index());
+        let entry_len = Self::entry_len::<T>();
+
+        if !self.iter.is_double_mapped() && index + entry_len > len {
+            let to_end = len - index;
+
+            if self.iter.available() < to_end + entry_len {
+                return Err(value);
+            }
+
+            unsafe {
+                // If the header does not fit, the consumer skips the slots anyway.
+                self.base_ptr().add(index).write_bytes(0, to_end);
+
+                if to_end >= header_len::<D>() {
+                    ptr::write_unaligned(self.base_ptr().add(index) as *mut usize, SKIP_MARKER);
+                }
+            }
+
+            unsafe { self.iter.advance(to_end) };
+            index = 0;
+        }
+
+        if self.iter.available() < entry_len {
+            return Err(value);
+        }
+
+        unsafe {
+            let entry = self.base_ptr().add(index);
+            let offset = value_offset::<D>(entry, align_of::<T>());
+            let value_ptr = entry.add(offset) as *mut T;
+
+            entry.write_bytes(0, entry_len);
+            value_ptr.write(value);
+
+            if coerce(&mut *value_ptr) as *mut D as *mut u8 != value_ptr as *mut u8 {
+                value_ptr.drop_in_place();
+                panic!("coerce must return a reference to the value it is given");
+            }
+
+            // Thin pointers and references are ABI-compatible, regardless of their pointee,
+            // so `coerce` can be called with a pointer to the value.
+            let coerce = mem::transmute::<fn(&mut T) -> &mut D, Coerce<D>>(coerce);
+
+            ptr::write_unaligned(entry as *mut usize, entry_len);
+            ptr::write_unaligned(entry.add(size_of::<usize>()) as *mut usize, offset);
+            ptr::write_unaligned(
+                entry.add(2 * size_of::<usize>()) as *mut usize,
+                align_of::<T>(),
+            );
+            ptr::write_unaligned(entry.add(3 * size_of::<usize>()) as *mut Coerce<D>, coerce);
+
+            self.iter.advance(entry_len);
+        }
+
+        Ok(())
+    }
+}
+
+#[doc = r##"
+Reads values written by a [`DynProducer`], as `&mut D`.
+
+This struct can only be created by calling [`ConsIter::dyn_consumer`], which is unsafe, as entries are trusted
+to be written by a [`DynProducer`] with the same `D`.
+"##]
+pub struct DynConsumer<'a, 'buf, B: MutRB<Item = u8>, const W: bool, D: ?Sized> {
+    iter: &'a mut ConsIter<'buf, B, W>,
+    phantom: PhantomData<*mut D>,
+}
+
+impl<'a, 'buf, B: MutRB<Item = u8>, const W: bool, D: ?Sized> DynConsumer<'a, 'buf, B, W, D> {
+    pub(crate) fn new(iter: &'a mut ConsIter<'buf, B, W>) -> Self {
+        Self {
+            iter,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Calls `f` with the next value, which is then dropped in place.
+    ///
+    /// Returns:
+    /// * `None`, if there are no values;
+    /// * `Some(r)`, where `r` is the result of `f`, otherwise.
+    pub fn pop_with<R>(&mut self, f: impl FnOnce(&mut D) -> R) -> Option<R> {
+        let base = self.iter.buffer().inner_mut().as_mut_ptr() as *mut u8;
+
+        loop {
+            if self.iter.available() == 0 {
+                return None;
+            }
+
+            let index = self.iter.index();
+            let to_end = self.iter.buf_len() - index;
+
+            if !self.iter.is_double_mapped() && to_end < header_len::<D>() {
+                unsafe { self.iter.advance(to_end) };
+                continue;
+            }
+
+            unsafe {
+                let entry = base.add(index);
+                let entry_len = ptr::read_unaligned(entry as *const usize);
+
+                if entry_len == SKIP_MARKER {
+                    self.iter.advance(to_end);
+                    continue;
+                }
+
+                let offset = ptr::read_unaligned(entry.add(size_of::<usize>()) as *const usize);
+                let align = ptr::read_unaligned(entry.add(2 * size_of::<usize>()) as *const usize);
+                let coerce =
+                    ptr::read_unaligned(entry.add(3 * size_of::<usize>()) as *const Coerce<D>);
+
+                // The buffer may have been moved since the value was written, so the value is realigned
+                // if needed, and the pointer is rebuilt from the current location of the entry.
+                let aligned = value_offset::<D>(entry, align);
+                if aligned != offset {
+                    let size = entry_len - header_len::<D>() - (align - 1);
+                    ptr::copy(entry.add(offset), entry.add(aligned), size);
+                }
+                let dyn_ptr = coerce(entry.add(aligned));
+
+                let ret = f(&mut *dyn_ptr);
+                dyn_ptr.drop_in_place();
+
+                self.iter.advance(entry_len);
+                return Some(ret);
+            }
+        }
+    }
+
+    /// Drops all the available values in place, returning their number.
+    pub fn clear(&mut self) -> usize {
+        let mut count = 0;
+
+        while self.pop_with(|_| ()).is_some() {
+            count += 1;
+        }
+
+        count
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>> ProdIter<'buf, B> {
+    /// Returns a [`DynProducer`], writing values of different types viewed as `D`.
+    ///
+    /// # Safety
+    /// All the bytes the producer writes must only be read by a [`DynConsumer`] with the same `D`,
+    /// as values may hold uninitialised padding bytes.
+    pub unsafe fn dyn_producer<D: ?Sized>(&mut self) -> DynProducer<'_, 'buf, B, D> {
+        DynProducer::new(self)
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>, const W: bool> ConsIter<'buf, B, W> {
+    /// Returns a [`DynConsumer`], reading values written by a [`DynProducer`].
+    ///
+    /// # Safety
+    /// All the bytes the consumer reads must have been written by a [`DynProducer`] with the same `D`.
+    pub unsafe fn dyn_consumer<D: ?Sized>(&mut self) -> DynConsumer<'_, 'buf, B, W, D> {
+        DynConsumer::new(self)
+    }
+}
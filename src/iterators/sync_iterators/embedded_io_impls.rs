@@ -0,0 +1,86 @@
+use crate::iterators::iterator_trait::MRBIterator;
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+use core::convert::Infallible;
+use embedded_io::{BufRead, ErrorType, Read, ReadReady, Write, WriteReady};
+
+impl<B: MutRB<Item = u8>, const W: bool> ErrorType for ConsIter<'_, B, W> {
+    type Error = Infallible;
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> Read for ConsIter<'_, B, W> {
+    /// Copies at most `buf.len()` available bytes into `buf`, advancing the iterator.
+    ///
+    /// Unless `buf` is empty, blocks the thread in a loop until there is at least one available byte.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.wait_for(1);
+
+        let count = self.available().min(buf.len());
+        self.copy_slice(&mut buf[..count]);
+        Ok(count)
+    }
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> BufRead for ConsIter<'_, B, W> {
+    /// Returns the first of the two slices holding available bytes.
+    ///
+    /// Blocks the thread in a loop until there is at least one available byte.
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        self.wait_for(1);
+
+        Ok(self.peek_available().unwrap().as_slices().0)
+    }
+
+    /// # Panics
+    /// If `amt` is greater than the number of available bytes.
+    fn consume(&mut self, amt: usize) {
+        assert!(
+            amt <= self.available(),
+            "cannot consume more than the available bytes"
+        );
+
+        unsafe { self.advance(amt) };
+    }
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> ReadReady for ConsIter<'_, B, W> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.available() > 0)
+    }
+}
+
+impl<B: MutRB<Item = u8>> ErrorType for ProdIter<'_, B> {
+    type Error = Infallible;
+}
+
+impl<B: MutRB<Item = u8>> Write for ProdIter<'_, B> {
+    /// Pushes at most `buf.len()` bytes from `buf`, as many as there are free slots.
+    ///
+    /// Unless `buf` is empty, blocks the thread in a loop until there is at least one free slot.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.wait_for(1);
+
+        let count = self.available().min(buf.len());
+        self.push_slice(&buf[..count]);
+        Ok(count)
+    }
+
+    /// Does nothing, as pushed bytes are immediately visible to the next iterator.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<B: MutRB<Item = u8>> WriteReady for ProdIter<'_, B> {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.available() > 0)
+    }
+}
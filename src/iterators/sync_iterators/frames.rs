@@ -0,0 +1,87 @@
+use crate::iterators::iterator_trait::FollowerIter;
+use crate::iterators::{ConsIter, SlicePairMut, WorkIter};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+
+#[doc = r##"
+Fixed-size frames of `N` items, yielded as arrays.
+
+This struct can only be created by calling [`WorkIter::frames`] or [`ConsIter::peek_frames`].
+
+Each frame is done with once the next one is requested: the iterator is then advanced by `N` items.
+The same happens when [`Self`] is dropped.
+
+Frames never straddle the end of the buffer: if the buffer is double mapped, it is contiguous;
+otherwise, the length of the buffer has to be a multiple of `N` and the iterator has to be aligned to it.
+"##]
+pub struct Frames<'a, I: FollowerIter, const N: usize> {
+    iter: &'a mut I,
+    pending: bool,
+}
+
+impl<I: FollowerIter, const N: usize> Drop for Frames<'_, I, N> {
+    fn drop(&mut self) {
+        self.hop_pending();
+    }
+}
+
+impl<'a, I: FollowerIter<Item = T>, T, const N: usize> Frames<'a, I, N> {
+    pub(crate) fn new(iter: &'a mut I) -> Self {
+        assert!(
+            N > 0 && N < iter.buf_len(),
+            "frame size must be between 1 and buf_len - 1"
+        );
+        assert!(
+            iter.is_double_mapped()
+                || (iter.buf_len().is_multiple_of(N) && iter.index().is_multiple_of(N)),
+            "buf_len and index must be multiples of the frame size"
+        );
+
+        Self {
+            iter,
+            pending: false,
+        }
+    }
+
+    #[inline]
+    fn hop_pending(&mut self) {
+        if self.pending {
+            self.pending = false;
+            unsafe { self.iter.advance(N) };
+        }
+    }
+
+    #[inline]
+    fn next_chunk(&mut self) -> Option<SlicePairMut<'_, T>> {
+        self.hop_pending();
+
+        let ret = self.iter.next_chunk_mut(N)?;
+        self.pending = true;
+
+        Some(ret)
+    }
+}
+
+#[inline]
+fn as_frame<T, const N: usize>(chunk: SlicePairMut<'_, T>) -> Option<&mut [T; N]> {
+    chunk.into_slices().0.try_into().ok()
+}
+
+impl<'buf, B: MutRB<Item = T>, T, const N: usize> Frames<'_, WorkIter<'buf, B>, N> {
+    /// Returns a mutable reference to the next frame, if there are enough available items.
+    #[inline]
+    pub fn next_frame(&mut self) -> Option<&mut [T; N]> {
+        as_frame(self.next_chunk()?)
+    }
+}
+
+impl<'buf, B: MutRB<Item = T>, T, const W: bool, const N: usize>
+    Frames<'_, ConsIter<'buf, B, W>, N>
+{
+    /// Returns a reference to the next frame, if there are enough available items.
+    ///
+    /// Items of the previous frame are dropped.
+    #[inline]
+    pub fn next_frame(&mut self) -> Option<&[T; N]> {
+        as_frame(self.next_chunk()?).map(|frame| &*frame)
+    }
+}
@@ -0,0 +1,446 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{
+    ConsIter, MessageError, MessageProducer, ProdIter, SlicePair, SlicePairMut,
+};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+
+/// Returns the index of the first occurrence of `byte` in `pair`, starting from `from`.
+#[inline]
+fn find_byte(pair: SlicePair<'_, u8>, from: usize, byte: u8) -> Option<usize> {
+    let (head, tail) = pair.as_slices();
+
+    match from < head.len() {
+        true => head[from..]
+            .iter()
+            .position(|b| *b == byte)
+            .map(|i| from + i)
+            .or_else(|| tail.iter().position(|b| *b == byte).map(|i| head.len() + i)),
+        false => tail[from - head.len()..]
+            .iter()
+            .position(|b| *b == byte)
+            .map(|i| from + i),
+    }
+}
+
+#[doc = r##"
+Frames of bytes terminated by a delimiter, yielded as [`SlicePair`]s.
+
+This struct can only be created by calling [`ConsIter::read_until`] or [`ConsIter::lines`].
+
+Each frame is done with once the next one is requested: the iterator is then advanced past the frame
+and its delimiter. The same happens when [`Self`] is dropped.
+
+Frames are only yielded once their delimiter is available, so a frame has to fit in the buffer.
+"##]
+pub struct Delimited<'a, 'buf, B: MutRB<Item = u8>, const W: bool> {
+    iter: &'a mut ConsIter<'buf, B, W>,
+    delim: u8,
+    strip_cr: bool,
+    scanned: usize,
+    pending: usize,
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> Drop for Delimited<'_, '_, B, W> {
+    fn drop(&mut self) {
+        self.hop_pending();
+    }
+}
+
+impl<'a, 'buf, B: MutRB<Item = u8>, const W: bool> Delimited<'a, 'buf, B, W> {
+    pub(crate) fn new(iter: &'a mut ConsIter<'buf, B, W>, delim: u8, strip_cr: bool) -> Self {
+        Self {
+            iter,
+            delim,
+            strip_cr,
+            scanned: 0,
+            pending: 0,
+        }
+    }
+
+    #[inline]
+    fn hop_pending(&mut self) {
+        let pending = core::mem::take(&mut self.pending);
+        unsafe { self.iter.advance(pending) };
+    }
+
+    /// Returns the next frame, without its delimiter, if it is complete.
+    ///
+    /// The previous frame is dropped.
+    pub fn next_frame(&mut self) -> Option<SlicePair<'_, u8>> {
+        self.hop_pending();
+
+        let avail = self.iter.peek_available()?;
+
+        let Some(end) = find_byte(avail, self.scanned, self.delim) else {
+            self.scanned = avail.len();
+            return None;
+        };
+
+        self.scanned = 0;
+        self.pending = end + 1;
+
+        let frame = avail.split_at(end).0;
+        match self.strip_cr && frame.get(end.wrapping_sub(1)) == Some(&b'\r') {
+            true => Some(frame.split_at(end - 1).0),
+            false => Some(frame),
+        }
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>, const W: bool> ConsIter<'buf, B, W> {
+    /// Returns a [`Delimited`], yielding frames terminated by `delim`.
+    pub fn read_until(&mut self, delim: u8) -> Delimited<'_, 'buf, B, W> {
+        Delimited::new(self, delim, false)
+    }
+
+    /// Returns a [`Delimited`], yielding lines terminated by `\n` or `\r\n`.
+    pub fn lines(&mut self) -> Delimited<'_, 'buf, B, W> {
+        Delimited::new(self, b'\n', true)
+    }
+}
+
+/// Error returned by [`FrameDecoder::decode_frame`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameError {
+    /// The frame is not correctly encoded. It has been dropped.
+    Malformed,
+    /// The decoded frame can never fit in the destination buffer. It has been dropped.
+    TooLarge,
+    /// There are not enough free slots in the destination buffer at the moment.
+    /// The frame is kept, so that decoding can be retried.
+    Full,
+}
+
+impl Display for FrameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::Malformed => f.write_str("the frame is malformed"),
+            FrameError::TooLarge => f.write_str("the frame exceeds the capacity of the buffer"),
+            FrameError::Full => f.write_str("not enough free slots for the frame"),
+        }
+    }
+}
+
+impl core::error::Error for FrameError {}
+
+impl From<MessageError> for FrameError {
+    fn from(value: MessageError) -> Self {
+        match value {
+            MessageError::TooLarge => FrameError::TooLarge,
+            MessageError::Full => FrameError::Full,
+        }
+    }
+}
+
+/// Trait implemented by byte-stuffing schemes.
+pub(crate) trait FrameCodec {
+    /// Byte terminating each encoded frame.
+    const DELIM: u8;
+
+    /// Returns the length of `frame` once encoded, delimiter included.
+    fn encoded_len(frame: &[u8]) -> usize;
+    /// Encodes `frame` into `dst`, which is [`Self::encoded_len`] long.
+    fn encode(frame: &[u8], dst: SlicePairMut<'_, u8>);
+    /// Returns the length of the encoded `frame` (delimiter excluded) once decoded,
+    /// or `None` if it is malformed.
+    fn decoded_len(frame: SlicePair<'_, u8>) -> Option<usize>;
+    /// Decodes the well-formed `frame` into `dst`, which is [`Self::decoded_len`] long.
+    fn decode(frame: SlicePair<'_, u8>, dst: SlicePairMut<'_, u8>);
+}
+
+/// Writes bytes into a [`SlicePairMut`] one by one.
+struct Cursor<'a> {
+    dst: SlicePairMut<'a, u8>,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(dst: SlicePairMut<'a, u8>) -> Self {
+        Self { dst, pos: 0 }
+    }
+
+    #[inline]
+    fn put(&mut self, byte: u8) {
+        *self.dst.get_mut(self.pos).unwrap() = byte;
+        self.pos += 1;
+    }
+}
+
+/// Serial Line Internet Protocol (RFC 1055).
+pub struct Slip;
+
+impl Slip {
+    const END: u8 = 0xC0;
+    const ESC: u8 = 0xDB;
+    const ESC_END: u8 = 0xDC;
+    const ESC_ESC: u8 = 0xDD;
+}
+
+impl FrameCodec for Slip {
+    const DELIM: u8 = Self::END;
+
+    fn encoded_len(frame: &[u8]) -> usize {
+        frame.len()
+            + frame
+                .iter()
+                .filter(|b| matches!(**b, Self::END | Self::ESC))
+                .count()
+            + 1
+    }
+
+    fn encode(frame: &[u8], dst: SlicePairMut<'_, u8>) {
+        let mut cursor = Cursor::new(dst);
+
+        for b in frame {
+            match *b {
+                Self::END => {
+                    cursor.put(Self::ESC);
+                    cursor.put(Self::ESC_END);
+                }
+                Self::ESC => {
+                    cursor.put(Self::ESC);
+                    cursor.put(Self::ESC_ESC);
+                }
+                b => cursor.put(b),
+            }
+        }
+
+        cursor.put(Self::END);
+    }
+
+    fn decoded_len(frame: SlicePair<'_, u8>) -> Option<usize> {
+        let mut len = 0;
+        let mut bytes = frame.iter();
+
+        while let Some(b) = bytes.next() {
+            if *b == Self::ESC && !matches!(bytes.next(), Some(&(Self::ESC_END | Self::ESC_ESC))) {
+                return None;
+            }
+            len += 1;
+        }
+
+        Some(len)
+    }
+
+    fn decode(frame: SlicePair<'_, u8>, dst: SlicePairMut<'_, u8>) {
+        let mut cursor = Cursor::new(dst);
+        let mut bytes = frame.iter();
+
+        while let Some(b) = bytes.next() {
+            match *b {
+                Self::ESC => match bytes.next() {
+                    Some(&Self::ESC_END) => cursor.put(Self::END),
+                    _ => cursor.put(Self::ESC),
+                },
+                b => cursor.put(b),
+            }
+        }
+    }
+}
+
+/// Consistent Overhead Byte Stuffing, with `0` as delimiter.
+pub struct Cobs;
+
+impl FrameCodec for Cobs {
+    const DELIM: u8 = 0;
+
+    fn encoded_len(frame: &[u8]) -> usize {
+        // Leading code and delimiter.
+        let mut len = 2;
+        let mut run = 0;
+
+        for (i, b) in frame.iter().enumerate() {
+            len += 1;
+            run += 1;
+
+            if *b == 0 {
+                run = 0;
+            } else if run == 254 && i + 1 < frame.len() {
+                len += 1;
+                run = 0;
+            }
+        }
+
+        len
+    }
+
+    fn encode(frame: &[u8], dst: SlicePairMut<'_, u8>) {
+        let mut cursor = Cursor::new(dst);
+        let mut code_pos = 0;
+        let mut code = 1u8;
+        cursor.put(0);
+
+        for (i, b) in frame.iter().enumerate() {
+            if *b != 0 {
+                cursor.put(*b);
+                code += 1;
+            }
+
+            // A full block at the end of the frame needs no further (empty) block.
+            if *b == 0 || (code == 0xFF && i + 1 < frame.len()) {
+                *cursor.dst.get_mut(code_pos).unwrap() = code;
+                code_pos = cursor.pos;
+                code = 1;
+                cursor.put(0);
+            }
+        }
+
+        *cursor.dst.get_mut(code_pos).unwrap() = code;
+        cursor.put(Self::DELIM);
+    }
+
+    fn decoded_len(frame: SlicePair<'_, u8>) -> Option<usize> {
+        let (mut i, mut len) = (0, 0);
+
+        while i < frame.len() {
+            let code = *frame.get(i).unwrap() as usize;
+            i += code;
+
+            if code == 0 || i > frame.len() {
+                return None;
+            }
+
+            len += code - 1;
+            if code < 0xFF && i < frame.len() {
+                len += 1;
+            }
+        }
+
+        Some(len)
+    }
+
+    fn decode(frame: SlicePair<'_, u8>, dst: SlicePairMut<'_, u8>) {
+        let mut cursor = Cursor::new(dst);
+        let mut i = 0;
+
+        while i < frame.len() {
+            let code = *frame.get(i).unwrap() as usize;
+
+            for j in i + 1..i + code {
+                cursor.put(*frame.get(j).unwrap());
+            }
+
+            i += code;
+            if code < 0xFF && i < frame.len() {
+                cursor.put(0);
+            }
+        }
+    }
+}
+
+#[doc = r##"
+Streaming decoder of byte-stuffed frames, such as [`SlipDecoder`] and [`CobsDecoder`].
+
+Encoded frames are read from a [`ConsIter`] and decoded frames are written, as messages, into a
+[`MessageProducer`]. Raw bytes are only consumed once a whole frame is available, so an encoded frame has to fit
+in the source buffer. Empty frames are skipped.
+
+A decoder keeps track of how far it has searched for a delimiter, so it has to be used with a single source,
+which should not be consumed by other means in between calls.
+"##]
+pub struct FrameDecoder<C> {
+    scanned: usize,
+    phantom: PhantomData<C>,
+}
+
+/// Decoder of [`Slip`] frames.
+pub type SlipDecoder = FrameDecoder<Slip>;
+/// Decoder of [`Cobs`] frames.
+pub type CobsDecoder = FrameDecoder<Cobs>;
+
+impl<C> Default for FrameDecoder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> FrameDecoder<C> {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self {
+            scanned: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[allow(private_bounds)]
+impl<C: FrameCodec> FrameDecoder<C> {
+    /// Decodes the next complete frame available in `src`, pushing it into `dst`.
+    ///
+    /// Returns:
+    /// * `Ok(Some(len))`, if a frame of `len` bytes has been decoded;
+    /// * `Ok(None)`, if there is no complete frame in `src`;
+    /// * `Err(e)`, if the frame could not be pushed. See [`FrameError`].
+    pub fn decode_frame<B: MutRB<Item = u8>, const W: bool, D: MutRB<Item = u8>>(
+        &mut self,
+        src: &mut ConsIter<'_, B, W>,
+        dst: &mut MessageProducer<'_, '_, D>,
+    ) -> Result<Option<usize>, FrameError> {
+        loop {
+            let Some(avail) = src.peek_available() else {
+                return Ok(None);
+            };
+
+            let Some(end) = find_byte(avail, self.scanned, C::DELIM) else {
+                self.scanned = avail.len();
+                return Ok(None);
+            };
+
+            self.scanned = 0;
+            if end == 0 {
+                unsafe { src.advance(1) };
+                continue;
+            }
+
+            let frame = avail.split_at(end).0;
+            let res = match C::decoded_len(frame) {
+                Some(len) => dst
+                    .push_with(0, len, |out| C::decode(frame, out))
+                    .map(|_| len)
+                    .map_err(FrameError::from),
+                None => Err(FrameError::Malformed),
+            };
+
+            if res == Err(FrameError::Full) {
+                self.scanned = end;
+            } else {
+                unsafe { src.advance(end + 1) };
+            }
+
+            return res.map(Some);
+        }
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>> ProdIter<'buf, B> {
+    #[inline]
+    fn push_encoded<C: FrameCodec>(&mut self, frame: &[u8]) -> Option<()> {
+        let len = C::encoded_len(frame);
+
+        C::encode(frame, self.next_chunk_mut(len)?);
+
+        unsafe { self.advance(len) };
+        Some(())
+    }
+
+    /// Tries to push `frame`, encoded with [`Slip`] and terminated by its delimiter.
+    ///
+    /// Returns:
+    /// * `None`, if there are not enough free slots for the encoded frame;
+    /// * `Some(())`, otherwise.
+    pub fn push_slip_frame(&mut self, frame: &[u8]) -> Option<()> {
+        self.push_encoded::<Slip>(frame)
+    }
+
+    /// Tries to push `frame`, encoded with [`Cobs`] and terminated by its delimiter.
+    ///
+    /// Returns:
+    /// * `None`, if there are not enough free slots for the encoded frame;
+    /// * `Some(())`, otherwise.
+    pub fn push_cobs_frame(&mut self, frame: &[u8]) -> Option<()> {
+        self.push_encoded::<Cobs>(frame)
+    }
+}
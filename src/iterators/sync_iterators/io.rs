@@ -0,0 +1,262 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+#[cfg(all(unix, feature = "fd"))]
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use core::mem::MaybeUninit;
+#[cfg(all(unix, feature = "fd"))]
+use libc::c_int;
+#[cfg(feature = "std")]
+use std::io::{self, ErrorKind, IoSliceMut, Read, Write};
+
+/// Error returned by [`ProdIter::read_from_fd`] and [`ConsIter::write_to_fd`].
+#[cfg_attr(doc, doc(cfg(all(unix, feature = "fd"))))]
+#[cfg(all(unix, feature = "fd"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FdError {
+    /// The file descriptor is non-blocking and not ready (`EAGAIN`/`EWOULDBLOCK`).
+    WouldBlock,
+    /// Any other error, holding the value of `errno`.
+    Os(c_int),
+}
+
+#[cfg(all(unix, feature = "fd"))]
+impl Display for FdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FdError::WouldBlock => f.write_str("the operation would block"),
+            FdError::Os(errno) => write!(f, "os error {errno}"),
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "fd"))]
+impl core::error::Error for FdError {}
+
+#[cfg(all(unix, feature = "fd"))]
+#[inline]
+pub(crate) fn errno() -> c_int {
+    unsafe {
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "dragonfly"
+        ))]
+        let errno = *libc::__error();
+        #[cfg(any(target_os = "android", target_os = "netbsd", target_os = "openbsd"))]
+        let errno = *libc::__errno();
+        #[cfg(not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "dragonfly",
+            target_os = "android",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )))]
+        let errno = *libc::__errno_location();
+
+        errno
+    }
+}
+
+/// Calls `f` until it is not interrupted by a signal, converting its result.
+#[cfg(all(unix, feature = "fd"))]
+pub(crate) fn retry(mut f: impl FnMut() -> libc::ssize_t) -> Result<usize, FdError> {
+    loop {
+        match f() {
+            -1 => match errno() {
+                libc::EINTR => continue,
+                e if e == libc::EAGAIN || e == libc::EWOULDBLOCK => {
+                    return Err(FdError::WouldBlock);
+                }
+                e => return Err(FdError::Os(e)),
+            },
+            n => return Ok(n as usize),
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "fd"))]
+#[inline]
+pub(crate) fn iovec(ptr: *const u8, len: usize) -> libc::iovec {
+    libc::iovec {
+        iov_base: ptr as *mut libc::c_void,
+        iov_len: len,
+    }
+}
+
+/// Zeroes `slots`, returning them as initialised bytes.
+#[cfg(feature = "std")]
+#[inline]
+fn zeroed(slots: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    unsafe {
+        slots.as_mut_ptr().write_bytes(0, slots.len());
+        &mut *(slots as *mut [MaybeUninit<u8>] as *mut [u8])
+    }
+}
+
+// Free slots may hold uninitialised bytes (e.g. the padding of values written by a `DynProducer`),
+// so they are never handed out as `&mut [u8]` unless zeroed first.
+
+impl<B: MutRB<Item = u8>> ProdIter<'_, B> {
+    /// Reads at most `max` bytes from `fd` straight into the free slots of the buffer, advancing the iterator
+    /// by the number of bytes read, which is returned.
+    ///
+    /// Free slots are filled with a single `readv` call, or with a single `read` when they do not
+    /// wrap around the end of the buffer (e.g. with double-mapped buffers).
+    ///
+    /// Returns:
+    /// * `Ok(0)`, if there are no free slots or the end of file has been reached;
+    /// * `Err(FdError::WouldBlock)`, if `fd` is non-blocking and there is nothing to read;
+    /// * `Err(FdError::Os(errno))`, if the call fails.
+    ///
+    /// Calls interrupted by a signal are retried.
+    #[cfg_attr(doc, doc(cfg(all(unix, feature = "fd"))))]
+    #[cfg(all(unix, feature = "fd"))]
+    pub fn read_from_fd(&mut self, fd: c_int, max: usize) -> Result<usize, FdError> {
+        let count = self.available().min(max);
+
+        let Some(mut slots) = self.reserve_uninit(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+        let (head, tail) = slots.as_mut_slices().into_slices();
+
+        // Slots are only passed to the kernel as raw pointers.
+        let read = retry(|| unsafe {
+            match tail.is_empty() {
+                true => libc::read(fd, head.as_mut_ptr() as _, head.len()),
+                false => {
+                    let iov = [
+                        iovec(head.as_ptr() as _, head.len()),
+                        iovec(tail.as_ptr() as _, tail.len()),
+                    ];
+                    libc::readv(fd, iov.as_ptr(), 2)
+                }
+            }
+        })?;
+
+        unsafe { slots.assume_init(read) };
+        Ok(slots.commit())
+    }
+
+    /// Same as [`Self::read_from_fd`], but reads from `reader` with a single
+    /// [`Read::read_vectored`] call.
+    ///
+    /// Returns `Ok(0)` if there are no free slots or the end of file has been reached.
+    /// Calls failing with [`ErrorKind::Interrupted`] are retried.
+    /// If `reader` claims to have read more bytes than it was given, [`ErrorKind::InvalidData`] is returned,
+    /// and the iterator is not moved.
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    pub fn read_from<R: Read + ?Sized>(&mut self, reader: &mut R, max: usize) -> io::Result<usize> {
+        let count = self.available().min(max);
+
+        let Some(mut slots) = self.reserve_uninit(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+        // `Read` can only be given initialised bytes.
+        let (head, tail) = slots.as_mut_slices().into_slices();
+        let (head, tail) = (zeroed(head), zeroed(tail));
+
+        let read = loop {
+            match reader.read_vectored(&mut [IoSliceMut::new(head), IoSliceMut::new(tail)]) {
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                res => break res?,
+            }
+        };
+
+        // `reader` is not trusted: moving past the provided slots would expose uninitialised bytes.
+        if read > count {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "reader returned more bytes than the provided buffer can hold",
+            ));
+        }
+
+        unsafe { slots.assume_init(read) };
+        Ok(slots.commit())
+    }
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> ConsIter<'_, B, W> {
+    /// Writes at most `max` available bytes to `fd` straight from the buffer, advancing the iterator
+    /// by the number of bytes written, which is returned.
+    ///
+    /// Available bytes are written with a single `writev` call, or with a single `write` when they do not
+    /// wrap around the end of the buffer (e.g. with double-mapped buffers).
+    ///
+    /// Returns:
+    /// * `Ok(0)`, if there are no available bytes;
+    /// * `Err(FdError::WouldBlock)`, if `fd` is non-blocking and not ready to be written;
+    /// * `Err(FdError::Os(errno))`, if the call fails.
+    ///
+    /// Calls interrupted by a signal are retried.
+    #[cfg_attr(doc, doc(cfg(all(unix, feature = "fd"))))]
+    #[cfg(all(unix, feature = "fd"))]
+    pub fn write_to_fd(&mut self, fd: c_int, max: usize) -> Result<usize, FdError> {
+        let count = self.available().min(max);
+
+        let Some(slices) = self.next_chunk(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+        let (head, tail) = slices.as_slices();
+
+        let written = retry(|| unsafe {
+            match tail.is_empty() {
+                true => libc::write(fd, head.as_ptr() as _, head.len()),
+                false => {
+                    let iov = [
+                        iovec(head.as_ptr(), head.len()),
+                        iovec(tail.as_ptr(), tail.len()),
+                    ];
+                    libc::writev(fd, iov.as_ptr(), 2)
+                }
+            }
+        })?;
+
+        unsafe { self.advance(written) };
+        Ok(written)
+    }
+
+    /// Same as [`Self::write_to_fd`], but writes to `writer` with a single
+    /// [`Write::write_vectored`] call.
+    ///
+    /// Returns `Ok(0)` if there are no available bytes.
+    /// Calls failing with [`ErrorKind::Interrupted`] are retried.
+    /// If `writer` claims to have written more bytes than it was given, [`ErrorKind::InvalidData`] is returned,
+    /// and the iterator is not moved.
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    pub fn write_to<Wr: Write + ?Sized>(
+        &mut self,
+        writer: &mut Wr,
+        max: usize,
+    ) -> io::Result<usize> {
+        let count = self.available().min(max);
+
+        let Some(slices) = self.next_chunk(count).filter(|s| !s.is_empty()) else {
+            return Ok(0);
+        };
+
+        let written = loop {
+            match writer.write_vectored(&slices.as_io_slices()) {
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                res => break res?,
+            }
+        };
+
+        // `writer` is not trusted: moving past the provided bytes would overstep the successor.
+        if written > count {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "writer returned more bytes than the provided buffer holds",
+            ));
+        }
+
+        unsafe { self.advance(written) };
+        Ok(written)
+    }
+}
@@ -0,0 +1,282 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, ProdIter, SlicePair, SlicePairMut};
+use crate::ring_buffer::variants::ring_buffer_trait::{MutRB, StorageManager};
+use core::fmt::{self, Display, Formatter};
+
+/// Length of the header preceding each message: a `u32` length followed by a `u32` tag,
+/// both little endian.
+pub const MESSAGE_HEADER_LEN: usize = 8;
+
+/// Length written in place of a real one, to tell the consumer to skip to the end of the buffer.
+const SKIP_MARKER: u32 = u32::MAX;
+
+/// Error returned by [`MessageProducer::push`] and [`MessageProducer::push_tagged`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageError {
+    /// The message can never fit in the buffer, not even when it is empty.
+    TooLarge,
+    /// There are not enough free slots at the moment.
+    Full,
+}
+
+impl Display for MessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::TooLarge => f.write_str("the message exceeds the capacity of the buffer"),
+            MessageError::Full => f.write_str("not enough free slots for the message"),
+        }
+    }
+}
+
+impl core::error::Error for MessageError {}
+
+/// A message read by a [`MessageConsumer`].
+#[derive(Clone, Copy, Debug)]
+pub struct Message<'a> {
+    tag: u32,
+    payload: SlicePair<'a, u8>,
+}
+
+impl<'a> Message<'a> {
+    /// Returns the tag the message has been pushed with.
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// Returns the payload of the message.
+    ///
+    /// The payload is contiguous if the buffer is double mapped, or if padding is enabled.
+    pub fn payload(&self) -> SlicePair<'a, u8> {
+        self.payload
+    }
+}
+
+#[inline]
+fn encode_header(len: u32, tag: u32) -> [u8; MESSAGE_HEADER_LEN] {
+    let mut header = [0; MESSAGE_HEADER_LEN];
+    header[..4].copy_from_slice(&len.to_le_bytes());
+    header[4..].copy_from_slice(&tag.to_le_bytes());
+    header
+}
+
+#[inline]
+fn decode_header(header: [u8; MESSAGE_HEADER_LEN]) -> (u32, u32) {
+    let (len, tag) = header.split_at(4);
+    (
+        u32::from_le_bytes(len.try_into().unwrap()),
+        u32::from_le_bytes(tag.try_into().unwrap()),
+    )
+}
+
+#[doc = r##"
+Writes variable-length messages into a buffer of bytes.
+
+This struct can only be created by calling [`ProdIter::messages`].
+
+Each message is made of a header of [`MESSAGE_HEADER_LEN`] bytes, holding the length of the payload and a tag,
+followed by the payload itself. Messages are written atomically: the consumer never sees a partial message.
+
+If padding is enabled with [`Self::with_padding`], messages never straddle the end of the buffer: when a message
+does not fit before the end, the remaining slots are skipped. The same setting has to be used on the
+[`MessageConsumer`] side.
+"##]
+pub struct MessageProducer<'a, 'buf, B: MutRB> {
+    iter: &'a mut ProdIter<'buf, B>,
+    padded: bool,
+}
+
+impl<'a, 'buf, B: MutRB<Item = u8>> MessageProducer<'a, 'buf, B> {
+    pub(crate) fn new(iter: &'a mut ProdIter<'buf, B>) -> Self {
+        Self {
+            iter,
+            padded: false,
+        }
+    }
+
+    /// Enables padding, so that payloads are always contiguous.
+    ///
+    /// Has no effect if the buffer is double mapped, as payloads are contiguous anyway.
+    pub fn with_padding(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    /// Returns the maximum length of a payload which can be pushed into the buffer.
+    pub fn max_payload_len(&self) -> usize {
+        (self.iter.buf_len() - 1)
+            .saturating_sub(self.iter.buffer().history_len())
+            .saturating_sub(MESSAGE_HEADER_LEN)
+            .min(SKIP_MARKER as usize - 1)
+    }
+
+    /// Same as [`Self::push_tagged`], with tag `0`.
+    #[inline]
+    pub fn push(&mut self, payload: &[u8]) -> Result<(), MessageError> {
+        self.push_tagged(0, payload)
+    }
+
+    /// Tries to push a message with the given tag and payload.
+    ///
+    /// Returns:
+    /// * `Err(MessageError::TooLarge)`, if the payload is longer than [`Self::max_payload_len`];
+    /// * `Err(MessageError::Full)`, if there are not enough free slots at the moment;
+    /// * `Ok(())`, otherwise.
+    ///
+    /// With padding, the slots before the end of the buffer may be skipped even if `Full` is returned.
+    #[inline]
+    pub fn push_tagged(&mut self, tag: u32, payload: &[u8]) -> Result<(), MessageError> {
+        self.push_with(tag, payload.len(), |mut slots| {
+            slots.copy_from_slice(payload)
+        })
+    }
+
+    /// Same as [`Self::push_tagged`], but the payload of `len` bytes is written in place by `f`,
+    /// which receives the slots reserved for it.
+    ///
+    /// `f` is only called if the message can be pushed.
+    pub fn push_with(
+        &mut self,
+        tag: u32,
+        len: usize,
+        f: impl FnOnce(SlicePairMut<'_, u8>),
+    ) -> Result<(), MessageError> {
+        if len > self.max_payload_len() {
+            return Err(MessageError::TooLarge);
+        }
+
+        let total = MESSAGE_HEADER_LEN + len;
+
+        if self.padded && !self.iter.is_double_mapped() {
+            self.pad(total)?;
+        }
+
+        let slots = self.iter.next_chunk_mut(total).ok_or(MessageError::Full)?;
+        let (mut header, body) = slots.split_at(MESSAGE_HEADER_LEN);
+
+        header.copy_from_slice(&encode_header(len as u32, tag));
+        f(body);
+
+        unsafe { self.iter.advance(total) };
+        Ok(())
+    }
+
+    /// Skips the slots before the end of the buffer, if a message of `total` bytes does not fit in them.
+    fn pad(&mut self, total: usize) -> Result<(), MessageError> {
+        let to_end = self.iter.buf_len() - self.iter.index();
+
+        if to_end >= total {
+            return Ok(());
+        }
+
+        let slots = self.iter.next_chunk_mut(to_end).ok_or(MessageError::Full)?;
+
+        // If the header does not fit, the consumer skips the slots anyway.
+        if to_end >= MESSAGE_HEADER_LEN {
+            slots
+                .split_at(MESSAGE_HEADER_LEN)
+                .0
+                .copy_from_slice(&encode_header(SKIP_MARKER, 0));
+        }
+
+        unsafe { self.iter.advance(to_end) };
+        Ok(())
+    }
+}
+
+#[doc = r##"
+Reads variable-length messages written by a [`MessageProducer`].
+
+This struct can only be created by calling [`ConsIter::messages`].
+
+Each message is done with once the next one is requested: the iterator is then advanced past it.
+The same happens when [`Self`] is dropped.
+"##]
+pub struct MessageConsumer<'a, 'buf, B: MutRB<Item = u8>, const W: bool> {
+    iter: &'a mut ConsIter<'buf, B, W>,
+    pending: usize,
+    padded: bool,
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> Drop for MessageConsumer<'_, '_, B, W> {
+    fn drop(&mut self) {
+        self.hop_pending();
+    }
+}
+
+impl<'a, 'buf, B: MutRB<Item = u8>, const W: bool> MessageConsumer<'a, 'buf, B, W> {
+    pub(crate) fn new(iter: &'a mut ConsIter<'buf, B, W>) -> Self {
+        Self {
+            iter,
+            pending: 0,
+            padded: false,
+        }
+    }
+
+    /// Enables padding. Has to match the setting of the [`MessageProducer`].
+    pub fn with_padding(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    #[inline]
+    fn hop_pending(&mut self) {
+        let pending = core::mem::take(&mut self.pending);
+        unsafe { self.iter.advance(pending) };
+    }
+
+    /// Returns the next message, if there is one.
+    ///
+    /// The previous message is dropped.
+    pub fn next_message(&mut self) -> Option<Message<'_>> {
+        self.hop_pending();
+
+        loop {
+            let avail = self.iter.available();
+            if avail == 0 {
+                return None;
+            }
+
+            let to_end = self.iter.buf_len() - self.iter.index();
+
+            if self.padded && !self.iter.is_double_mapped() && to_end < MESSAGE_HEADER_LEN {
+                unsafe { self.iter.advance(to_end) };
+                continue;
+            }
+
+            let mut header = [0; MESSAGE_HEADER_LEN];
+            self.iter
+                .next_chunk(MESSAGE_HEADER_LEN)?
+                .copy_to_slice(&mut header);
+
+            let (len, tag) = decode_header(header);
+
+            if len == SKIP_MARKER {
+                unsafe { self.iter.advance(to_end) };
+                continue;
+            }
+
+            let total = MESSAGE_HEADER_LEN + len as usize;
+            let chunk = self.iter.next_chunk(total)?;
+            self.pending = total;
+
+            return Some(Message {
+                tag,
+                payload: chunk.split_at(MESSAGE_HEADER_LEN).1,
+            });
+        }
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>> ProdIter<'buf, B> {
+    /// Returns a [`MessageProducer`], writing length-prefixed messages.
+    pub fn messages(&mut self) -> MessageProducer<'_, 'buf, B> {
+        MessageProducer::new(self)
+    }
+}
+
+impl<'buf, B: MutRB<Item = u8>, const W: bool> ConsIter<'buf, B, W> {
+    /// Returns a [`MessageConsumer`], reading messages written by a [`MessageProducer`].
+    pub fn messages(&mut self) -> MessageConsumer<'_, 'buf, B, W> {
+        MessageConsumer::new(self)
+    }
+}
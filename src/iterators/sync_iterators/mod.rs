@@ -1,6 +1,24 @@
 //! Sync iterators.
 
+pub(crate) mod bip_iter;
+#[cfg(feature = "bytes")]
+mod buf_impls;
 pub(crate) mod cons_iter;
+#[cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd"))]
+pub(crate) mod datagram;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
 pub(crate) mod detached;
+pub(crate) mod dyn_entries;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impls;
+pub(crate) mod frames;
+pub(crate) mod framing;
+#[cfg(any(feature = "std", all(unix, feature = "fd")))]
+pub(crate) mod io;
+pub(crate) mod messages;
+#[cfg(feature = "bytemuck")]
+mod pod;
 pub(crate) mod prod_iter;
+pub(crate) mod uninit_prod_iter;
 pub(crate) mod work_iter;
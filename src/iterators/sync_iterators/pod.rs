@@ -0,0 +1,53 @@
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+use bytemuck::{AnyBitPattern, NoUninit};
+use core::mem::MaybeUninit;
+
+impl<B: MutRB<Item = u8>> ProdIter<'_, B> {
+    /// Tries to push the bytes of `value`, which may end up split across the end of the buffer.
+    ///
+    /// No alignment is required in the buffer: values are written unaligned.
+    ///
+    /// Returns:
+    /// * `None`, if there are fewer than `size_of::<T>()` free slots;
+    /// * `Some(())`, otherwise.
+    #[cfg_attr(doc, doc(cfg(feature = "bytemuck")))]
+    #[inline]
+    pub fn push_pod<T: NoUninit>(&mut self, value: &T) -> Option<()> {
+        self.push_slice(bytemuck::bytes_of(value))
+    }
+}
+
+impl<B: MutRB<Item = u8>, const W: bool> ConsIter<'_, B, W> {
+    /// Reads a `T` from the next `size_of::<T>()` available bytes, without advancing the iterator.
+    ///
+    /// Works even if the bytes wrap around the end of the buffer.
+    ///
+    /// Returns `None` if there are fewer than `size_of::<T>()` available bytes.
+    #[cfg_attr(doc, doc(cfg(feature = "bytemuck")))]
+    pub fn peek_pod<T: AnyBitPattern>(&mut self) -> Option<T> {
+        let (head, tail) = self.next_chunk(size_of::<T>())?.as_slices();
+        let mut value = MaybeUninit::<T>::uninit();
+
+        unsafe {
+            let dst = value.as_mut_ptr() as *mut u8;
+            dst.copy_from_nonoverlapping(head.as_ptr(), head.len());
+            dst.add(head.len())
+                .copy_from_nonoverlapping(tail.as_ptr(), tail.len());
+
+            // Every byte has been written, and any bit pattern is a valid `T`.
+            Some(value.assume_init())
+        }
+    }
+
+    /// Same as [`Self::peek_pod`], but the iterator is advanced past the bytes which have been read.
+    #[cfg_attr(doc, doc(cfg(feature = "bytemuck")))]
+    #[inline]
+    pub fn pop_pod<T: AnyBitPattern>(&mut self) -> Option<T> {
+        let value = self.peek_pod()?;
+
+        unsafe { self.advance(size_of::<T>()) };
+        Some(value)
+    }
+}
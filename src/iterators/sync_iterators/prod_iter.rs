@@ -1,11 +1,17 @@
 #[cfg(doc)]
-use {crate::iterators::ConsIter, crate::iterators::Detached, core::mem::MaybeUninit};
-
-use crate::iterators::iterator_trait::{MRBIterator, MutableSlice, PrivateMRBIterator};
-use crate::iterators::{copy_from_slice_unchecked, private_impl};
+use {
+    crate::iterators::ConsIter, crate::iterators::Detached,
+    crate::ring_buffer::wrappers::unsafe_sync_cell::UnsafeSyncCell,
+};
+
+use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator, Role, debug_iter};
+use crate::iterators::{SlicePairMut, copy_from_slice_unchecked, private_impl};
+use crate::ring_buffer::storage::MRBIndex;
 use crate::ring_buffer::variants::ring_buffer_trait::{ConcurrentRB, IterManager, MutRB};
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
-use crate::ring_buffer::wrappers::unsafe_sync_cell::UnsafeSyncCell;
+use core::fmt;
+use core::mem::{MaybeUninit, needs_drop};
+use core::{ptr, slice};
 
 #[doc = r##"
 Iterator used to push data into the buffer.
@@ -15,44 +21,23 @@ When working with types which implement both
 [`Clone`](https://doc.rust-lang.org/std/clone/trait.Clone.html) traits, `copy` methods should be
 preferred over `clone` methods.
 
-# TL;DR about uninitialised memory
-If you created the buffer with either `default` or `from` methods and you are *not* going to use [`ConsIter::pop`],
-then it is safe to use normal methods from this struct.
-
-If you either created the buffer with `new_zeroed` or are going to move items out of the buffer (e.g. using [`ConsIter::pop`]),
-then you must pay attention to what you do and ensure that all the locations cleared by `pop` will be re-filled with `*_init` methods.
-After that you can use normal methods again.
-Read below to know why and how.
-
-It would be a good idea to do a check with [miri](https://github.com/rust-lang/miri), which is able to
-tell if and when something bad has happened.
-
-# A note about how this buffer is made:
+# A note about initialisation
 Every element in this buffer is wrapped in an [`UnsafeSyncCell`], which in the end is a [`MaybeUninit`].
-`MaybeUninit` (read the official docs if you want to know more) is a way to deal with possibly uninitialised data.
-
-When one creates a buffer from this crate, they may choose to build it either with `default` and `from` methods,
-or with `new_zeroed` ones.
+The buffer keeps track of which locations hold a live item: only the ones between the consumer and the producer do.
+Locations ahead of the producer are always treated as uninitialised, so every method in this struct writes
+items without reading or dropping the old content, and no leak nor double drop can happen.
 
-When using the former, an initialised buffer is created, so there are no problems concerning uninitialised memory.
-With the latter methods, a zeroed buffer is rather created.
-To write data into an uninitialised (or zeroed) block of memory, one has to use [`write`](https://doc.rust-lang.org/std/primitive.pointer.html#method.write)
-method, which overwrites a memory location, without reading or dropping the old value.
+Items are dropped either when the consumer passes over them without moving them out (see [`ConsIter`]), or when
+the buffer itself is dropped. Thus, moving items out of the buffer with [`ConsIter::pop`] is always safe.
 
-Remember that a zeroed location must *never* be read or dropped. Doing so would cause UB!
-
-On the other hand, using `write` on an initialised block doesn't drop the old value, causing then a memory leak.
-
-For each of the methods in this struct, there exists a `*_init` one (e.g. [`Self::push`] and [`Self::push_init`]).
-Normal methods are faster than `*_init` ones, and should be preferred over these when dealing with *surely*
-initialised memory.
-
-On the other hand, `*_init methods` always perform a check over the memory they are going to write and choose the proper way to
-deal it, even dropping the old value, if there is the need. So they are safe to use upon a possibly uninitialised block.
+# Writing into uninitialised slots
+[`Self::push_with`] and [`Self::reserve_uninit`] hand out slots as [`MaybeUninit`], so that items can be
+constructed in place, without being copied from the stack.
 "##]
 pub struct ProdIter<'buf, B: MutRB> {
     index: usize,
     cached_avail: usize,
+    pos: u64,
     buffer: BufRef<'buf, B>,
 }
 
@@ -65,21 +50,11 @@ impl<B: MutRB + IterManager> Drop for ProdIter<'_, B> {
 }
 
 impl<B: MutRB<Item = T>, T> PrivateMRBIterator<T> for ProdIter<'_, B> {
+    const ROLE: Role = Role::Prod;
+
     #[inline]
     fn _available(&mut self) -> usize {
-        let succ_idx = self.succ_index();
-
-        unsafe {
-            self.cached_avail = match self.index < succ_idx {
-                true => succ_idx.unchecked_sub(self.index).unchecked_sub(1),
-                false => self
-                    .buf_len()
-                    .unchecked_sub(self.index)
-                    .unchecked_add(succ_idx)
-                    .unchecked_sub(1),
-            };
-        }
-
+        self.cached_avail = self.available_uncached();
         self.cached_avail
     }
 
@@ -93,6 +68,16 @@ impl<B: MutRB<Item = T>, T> PrivateMRBIterator<T> for ProdIter<'_, B> {
         self.buffer.cons_index()
     }
 
+    #[inline]
+    unsafe fn release_local(&self) {
+        let (atomic, len) = (self.buffer.prod_index(), self.buffer.inner_len());
+
+        unsafe {
+            self.buffer
+                .drop_range(atomic, (self.index + len - atomic) % len)
+        };
+    }
+
     private_impl!();
 }
 
@@ -100,20 +85,57 @@ impl<B: MutRB<Item = T>, T> MRBIterator for ProdIter<'_, B> {
     type Item = T;
 }
 
+impl<B: MutRB<Item = T>, T> fmt::Debug for ProdIter<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_iter(self, "ProdIter", f)
+    }
+}
+
 impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
     pub(crate) fn new(value: BufRef<'buf, B>) -> Self {
+        let (index, len) = (value.prod_index(), value.inner_len());
+
         Self {
-            index: 0,
+            index,
+            // Positions count from the consumer, which starts at `0`.
+            pos: ((index + len - value.cons_index()) % len) as u64,
             buffer: value,
             cached_avail: 0,
         }
     }
 
+    /// Returns a [`StatsHandle`](crate::StatsHandle), reading the statistics of the buffer from any thread.
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats_handle(&self) -> crate::StatsHandle<'buf, B> {
+        crate::StatsHandle::new(&self.buffer)
+    }
+
+    /// Same as [`MRBIterator::available`], but does not need `&mut self`, as the result is not cached.
     #[inline]
-    fn _push(&mut self, value: T, f: fn(*mut T, T)) -> Result<(), T> {
-        if let Some(binding) = self.next_ref_mut_init() {
-            f(binding, value);
-            unsafe { self.advance(1) };
+    pub(crate) fn available_uncached(&self) -> usize {
+        let succ_idx = self.succ_index();
+
+        unsafe {
+            match self.index < succ_idx {
+                true => succ_idx.unchecked_sub(self.index).unchecked_sub(1),
+                false => self
+                    .buf_len()
+                    .unchecked_sub(self.index)
+                    .unchecked_add(succ_idx)
+                    .unchecked_sub(1),
+            }
+            .saturating_sub(self.buffer.history_len())
+        }
+    }
+
+    /// Writes `value` into the next slot, without advancing the iterator.
+    #[inline]
+    pub(crate) fn write_next(&mut self, value: T) -> Result<(), T> {
+        let next = self.next_ref_mut_init();
+
+        if let Some(binding) = self.record(next) {
+            unsafe { binding.write(value) };
             Ok(())
         } else {
             Err(value)
@@ -122,90 +144,40 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
 
     /// Tries to push a new item by moving or copying it.
     ///
-    /// This method must *not* be used to push items after a [`ConsIter::pop`].
-    /// In this case, [`Self::push_init`] has to be used, instead.
-    /// For more info, refer to the main documentation above.
-    ///
     /// Returns:
     /// * `Err(value)`, if the buffer is full;
     /// * `Ok(())`, otherwise.
     #[inline]
     pub fn push(&mut self, value: T) -> Result<(), T> {
-        fn f<T>(binding: *mut T, value: T) {
-            unsafe {
-                *binding = value;
-            }
-        }
+        self.write_next(value)?;
 
-        self._push(value, f)
+        unsafe { self.advance(1) };
+        Ok(())
     }
 
-    /// Same as [`Self::push_slice`], but can be used when dealing with possibly uninitialised
-    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
-    ///
-    /// Returns:
-    /// * `Err(value)`, if the buffer is full;
-    /// * `Ok(())`, otherwise.
+    /// Same as [`Self::push`].
+    #[deprecated(since = "1.0.0", note = "use `push` instead")]
     #[inline]
     pub fn push_init(&mut self, value: T) -> Result<(), T> {
-        fn f<T>(binding: *mut T, value: T) {
-            unsafe {
-                if UnsafeSyncCell::check_zeroed(binding) {
-                    binding.write(value);
-                } else {
-                    *binding = value;
-                }
-            }
-        }
-
-        self._push(value, f)
+        self.push(value)
     }
 
-    #[cfg(feature = "vmem")]
+    /// Writes `slice` into the next slots using `f`, without advancing the iterator.
     #[inline]
-    fn _push_slice(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> Option<()> {
-        let count = slice.len();
+    pub(crate) fn write_slice(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> Option<()> {
+        let chunk = self.next_chunk_mut(slice.len());
+        let (binding_h, binding_t) = self.record(chunk)?.into_slices();
 
-        if let Some(binding) = self.next_chunk_mut(count) {
-            f(binding, slice);
+        let (slice_h, slice_t) = slice.split_at(binding_h.len());
+        f(binding_h, slice_h);
+        f(binding_t, slice_t);
 
-            unsafe { self.advance(count) };
-            Some(())
-        } else {
-            None
-        }
-    }
-
-    #[cfg(not(feature = "vmem"))]
-    #[inline]
-    fn _push_slice(&mut self, slice: &[T], f: fn(&mut [T], &[T])) -> Option<()> {
-        let count = slice.len();
-
-        if let Some((binding_h, binding_t)) = self.next_chunk_mut(count) {
-            let mid = binding_h.len();
-            if mid == slice.len() {
-                f(binding_h, slice);
-            } else {
-                unsafe {
-                    f(binding_h, slice.get_unchecked(..mid));
-                    f(binding_t, slice.get_unchecked(mid..));
-                }
-            }
-
-            unsafe { self.advance(count) };
-            Some(())
-        } else {
-            None
-        }
+        Some(())
     }
 
     /// Tries to push a slice of items by copying the elements.
     /// The elements must implement [`Copy`](https://doc.rust-lang.org/std/marker/trait.Copy.html) trait.
     ///
-    /// This method must *not* be used to push items after a [`ConsIter::pop`].
-    /// In this case, [`Self::push_slice_init`] has to be used, instead.
-    /// For more info, refer to the main documentation above.
-    ///
     /// Returns:
     /// * `None`, if the buffer is full;
     /// * `Some(())`, otherwise.
@@ -214,48 +186,25 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
     where
         T: Copy,
     {
-        #[inline]
-        fn f<T: Copy>(binding: &mut [T], slice: &[T]) {
-            copy_from_slice_unchecked(slice, binding);
-        }
+        self.write_slice(slice, copy_into)?;
 
-        self._push_slice(slice, f)
+        unsafe { self.advance(slice.len()) };
+        Some(())
     }
 
-    /// Same as [`Self::push_slice`], but can be used when dealing with possibly uninitialised
-    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
-    ///
-    /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// Same as [`Self::push_slice`].
+    #[deprecated(since = "1.0.0", note = "use `push_slice` instead")]
     #[inline]
     pub fn push_slice_init(&mut self, slice: &[T]) -> Option<()>
     where
         T: Copy,
     {
-        #[inline]
-        fn f<T: Copy>(binding_h: &mut [T], slice: &[T]) {
-            for (x, y) in binding_h.iter_mut().zip(slice) {
-                if UnsafeSyncCell::check_zeroed(x as *mut T) {
-                    unsafe {
-                        (x as *mut T).write(*y);
-                    }
-                } else {
-                    *x = *y;
-                }
-            }
-        }
-
-        self._push_slice(slice, f)
+        self.push_slice(slice)
     }
 
     /// Tries to push a slice of items by cloning the elements.
     /// The elements must implement [`Clone`](https://doc.rust-lang.org/std/clone/trait.Clone.html) trait.
     ///
-    /// This method must *not* be used to push items after a [`ConsIter::pop`].
-    /// In this case, [`Self::push_slice_clone_init`] has to be used, instead.
-    /// For more info, refer to the main documentation above.
-    ///
     /// Returns:
     /// * `None`, if the buffer is full;
     /// * `Some(())`, otherwise.
@@ -264,48 +213,74 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
     where
         T: Clone,
     {
-        #[inline]
-        fn f<T: Clone>(binding_h: &mut [T], slice: &[T]) {
-            binding_h.clone_from_slice(slice);
-        }
+        self.write_slice(slice, clone_into)?;
 
-        self._push_slice(slice, f)
+        unsafe { self.advance(slice.len()) };
+        Some(())
     }
 
-    /// Same as [`Self::push_slice_clone`], but can be used when dealing with possibly uninitialised
-    /// locations within the buffer, e.g. after a [`ConsIter::pop`].
-    ///
-    /// Returns:
-    /// * `None`, if the buffer is full;
-    /// * `Some(())`, otherwise.
+    /// Same as [`Self::push_slice_clone`].
+    #[deprecated(since = "1.0.0", note = "use `push_slice_clone` instead")]
     #[inline]
     pub fn push_slice_clone_init(&mut self, slice: &[T]) -> Option<()>
     where
         T: Clone,
     {
-        #[inline]
-        fn f<T: Clone>(binding_h: &mut [T], slice: &[T]) {
-            for (x, y) in binding_h.iter_mut().zip(slice) {
-                if UnsafeSyncCell::check_zeroed(x as *mut T) {
-                    unsafe {
-                        (x as *mut T).write(y.clone());
-                    }
-                } else {
-                    x.clone_from(y);
-                }
-            }
+        self.push_slice_clone(slice)
+    }
+
+    /// Tries to push a new item by constructing it in place.
+    ///
+    /// `f` receives the next slot as a [`MaybeUninit`] and must initialise it, returning the
+    /// reference yielded by [`MaybeUninit::write`] (or [`MaybeUninit::assume_init_mut`], when the
+    /// value is built field by field). This makes it possible to push large items without copying
+    /// them from the stack.
+    ///
+    /// Returns:
+    /// * `Err(f)`, if the buffer is full;
+    /// * `Ok(())`, otherwise.
+    ///
+    /// # Panics
+    /// Panics if the reference returned by `f` does not point to the slot it received.
+    pub fn push_with<F>(&mut self, f: F) -> Result<(), F>
+    where
+        F: FnOnce(&mut MaybeUninit<T>) -> &mut T,
+    {
+        let ok = self.check(1).then_some(());
+        if self.record(ok).is_none() {
+            return Err(f);
         }
 
-        self._push_slice(slice, f)
+        let ptr = self.buffer.inner()._index(self.index).as_mut_ptr();
+        let written: *mut T = f(unsafe { &mut *(ptr as *mut MaybeUninit<T>) });
+
+        assert!(
+            ptr::eq(written, ptr),
+            "the closure passed to `push_with` must return a reference to the slot it received"
+        );
+
+        unsafe { self.advance(1) };
+        Ok(())
     }
 
-    /// If available, returns a mutable reference to the next item.
-    /// This reference can be used to write data into an *initialised* item.
+    /// Reserves `count` slots, yielding an [`UninitSlots`] guard through which they can be initialised.
     ///
-    /// Items can be initialised by calling [`Self::get_next_item_mut_init`] or by creating a buffer
-    /// using `default` constructor. E.g.: `ConcurrentHeapRB::default` or `LocalStackRB::default`.
+    /// Initialised items become visible to the next iterator only when [`UninitSlots::commit`] is called.
     ///
-    /// For uninitialised items, use [`Self::get_next_item_mut_init`], instead.
+    /// Returns `None` if there are less than `count` free slots.
+    pub fn reserve_uninit<'a>(&'a mut self, count: usize) -> Option<UninitSlots<'a, 'buf, B>> {
+        let ok = self.check(count).then_some(());
+        self.record(ok)?;
+
+        Some(UninitSlots {
+            start: self.index,
+            len: count,
+            init: 0,
+            iter: self,
+        })
+    }
+
+    /// If available, returns a mutable reference to the next item.
     ///
     /// <div class="warning">
     ///
@@ -314,32 +289,23 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
     /// </div>
     ///
     /// # Safety
-    /// The retrieved item must be initialised! For more info, refer to [`MaybeUninit::assume_init_mut`](https://doc.rust-lang.org/std/mem/union.MaybeUninit.html#method.assume_init_mut).
+    /// Locations ahead of the producer never hold a live item, so the retrieved reference must only be used
+    /// to write a new one with [`ptr::write`](https://doc.rust-lang.org/std/ptr/fn.write.html), without reading or
+    /// dropping the old content. [`Self::get_next_item_mut_init`] or [`Self::push_with`] should be preferred.
     pub unsafe fn get_next_item_mut<'a>(&mut self) -> Option<&'a mut T> {
         self.next_ref_mut()
     }
 
     /// If available, returns a mutable pointer to the next item.
-    /// This pointer can be used to write data into the item, even if this is not already initialised.
-    /// It is important to note that reading from this pointer or turning it into a reference is still
-    /// undefined behavior, unless the item is initialized.
-    ///
-    /// If the memory pointed by this pointer is already initialised, it is possible to write into it
-    /// with a simple:
-    /// ```ignore
-    /// *ptr = value;
-    /// ```
-    /// Doing so, the old value will be automatically dropped and no leak will be created.
+    /// This pointer can be used to write data into the item, which is always uninitialised.
     ///
-    /// If the memory is not initialised, the write must be done with:
+    /// The write must be done with:
     /// ```ignore
     /// ptr.write(value);
     /// ```
     /// The reason is that `write` does not drop the old value, which is good, because dropping an
     /// uninitialised value is UB!
     ///
-    /// One should be able to test whether a piece of memory is initialised with [`UnsafeSyncCell::check_zeroed`].
-    ///
     /// For more info, refer to [`MaybeUninit::as_mut_ptr`](https://doc.rust-lang.org/std/mem/union.MaybeUninit.html#method.as_mut_ptr).
     /// <div class="warning">
     ///
@@ -350,11 +316,8 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
         self.next_ref_mut_init()
     }
 
-    /// If available, returns two mutable slices with a total count equal to `count`.
-    /// These references can be used to write data into *initialised* items.
-    ///
-    /// Items can be initialised (one by one) by calling [`Self::get_next_item_mut_init`] or by creating a buffer
-    /// using `default` constructor. E.g.: `ConcurrentHeapRB::default` or `LocalStackRB::default`.
+    /// If available, returns a [`SlicePairMut`] with len equal to `count`.
+    /// These references can be used to write data into the next items.
     ///
     /// <div class="warning">
     ///
@@ -363,12 +326,156 @@ impl<'buf, B: MutRB<Item = T>, T> ProdIter<'buf, B> {
     /// </div>
     ///
     /// # Safety
-    /// The retrieved items must be initialised! For more info, refer to [`MaybeUninit::assume_init_mut`](https://doc.rust-lang.org/std/mem/union.MaybeUninit.html#method.assume_init_mut).
-    pub unsafe fn get_next_slices_mut<'a>(&mut self, count: usize) -> Option<MutableSlice<'a, T>> {
+    /// Locations ahead of the producer never hold a live item, so the retrieved slices must only be used
+    /// to write new ones, without reading or dropping the old content.
+    /// [`Self::reserve_uninit`] should be preferred.
+    pub unsafe fn get_next_slices_mut<'a>(&mut self, count: usize) -> Option<SlicePairMut<'a, T>> {
         self.next_chunk_mut(count)
     }
 }
 
+/// Copies `slice` into uninitialised `binding`.
+#[inline]
+pub(crate) fn copy_into<T: Copy>(binding: &mut [T], slice: &[T]) {
+    copy_from_slice_unchecked(slice, binding);
+}
+
+/// Clones `slice` into uninitialised `binding`, without dropping the old content.
+#[inline]
+pub(crate) fn clone_into<T: Clone>(binding: &mut [T], slice: &[T]) {
+    for (x, y) in binding.iter_mut().zip(slice) {
+        unsafe { (x as *mut T).write(y.clone()) };
+    }
+}
+
+/// Guard returned by [`ProdIter::reserve_uninit`].
+///
+/// Slots are initialised in order, either one by one with [`Self::write`], or in bulk through
+/// [`Self::as_mut_slices`] and [`Self::assume_init`].
+/// Calling [`Self::commit`] advances the producer past the initialised slots, making them visible to the next iterator.
+///
+/// If the guard is dropped without being committed, the initialised items are dropped, and the producer does not move.
+pub struct UninitSlots<'a, 'buf, B: MutRB> {
+    iter: &'a mut ProdIter<'buf, B>,
+    start: usize,
+    len: usize,
+    init: usize,
+}
+
+impl<B: MutRB> Drop for UninitSlots<'_, '_, B> {
+    fn drop(&mut self) {
+        if needs_drop::<B::Item>() {
+            for i in 0..self.init {
+                unsafe { self.slot(i).drop_in_place() };
+            }
+        }
+    }
+}
+
+impl<B: MutRB<Item = T>, T> UninitSlots<'_, '_, B> {
+    #[inline]
+    fn slot(&self, i: usize) -> *mut T {
+        let len = self.iter.buffer.inner_len();
+
+        self.iter
+            .buffer
+            .inner()
+            ._index((self.start + i) % len)
+            .as_mut_ptr()
+    }
+
+    /// Returns the number of reserved slots.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no slot has been reserved.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of slots initialised so far.
+    #[inline]
+    pub fn initialised(&self) -> usize {
+        self.init
+    }
+
+    /// Returns the reserved slots as two [`MaybeUninit`] slices, the sum of which with len equal to [`Self::len`].
+    ///
+    /// Once written, slots have to be marked as initialised with [`Self::assume_init`].
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> SlicePairMut<'_, MaybeUninit<T>> {
+        let head_len = self.len.min(self.iter.buffer.inner_len() - self.start);
+
+        // Slices are built straight from the raw pointers, so that no `&mut [T]`
+        // is ever created over uninitialised slots.
+        unsafe {
+            SlicePairMut::new(
+                slice::from_raw_parts_mut(self.slot(0) as *mut MaybeUninit<T>, head_len),
+                slice::from_raw_parts_mut(
+                    self.iter.buffer.inner()._index(0).as_mut_ptr() as *mut MaybeUninit<T>,
+                    self.len - head_len,
+                ),
+            )
+        }
+    }
+
+    /// Initialises the next slot with `value`.
+    ///
+    /// Returns:
+    /// * `Err(value)`, if all the reserved slots are already initialised;
+    /// * `Ok(())`, otherwise.
+    #[inline]
+    pub fn write(&mut self, value: T) -> Result<(), T> {
+        if self.init == self.len {
+            return Err(value);
+        }
+
+        unsafe { self.slot(self.init).write(value) };
+        self.init += 1;
+
+        Ok(())
+    }
+
+    /// Marks the first `count` reserved slots as initialised.
+    ///
+    /// If `count` is less than [`Self::initialised`], the items beyond it are dropped.
+    ///
+    /// # Safety
+    /// The first `count` slots must have been initialised, e.g. via [`Self::as_mut_slices`].
+    ///
+    /// # Panics
+    /// Panics if `count` is greater than [`Self::len`].
+    #[inline]
+    pub unsafe fn assume_init(&mut self, count: usize) {
+        assert!(
+            count <= self.len,
+            "count must not exceed the number of reserved slots"
+        );
+
+        if needs_drop::<T>() {
+            for i in count..self.init {
+                unsafe { self.slot(i).drop_in_place() };
+            }
+        }
+
+        self.init = count;
+    }
+
+    /// Advances the producer past the initialised slots, returning their number.
+    #[inline]
+    pub fn commit(mut self) -> usize {
+        let count = self.init;
+
+        unsafe { self.iter.advance(count) };
+        self.init = 0;
+
+        count
+    }
+}
+
 pub mod test {
     #[test]
     fn cached_avail() {
@@ -377,7 +484,7 @@ pub mod test {
 
         const BUFFER_SIZE: usize = 4095;
 
-        let buf = ConcurrentHeapRB::<u32>::default(BUFFER_SIZE + 1);
+        let buf = ConcurrentHeapRB::<u32>::new_zeroed(BUFFER_SIZE + 1);
         let (mut prod, mut cons) = buf.split();
 
         assert_eq!(prod.cached_avail, 0);
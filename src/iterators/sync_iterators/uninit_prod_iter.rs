@@ -0,0 +1,90 @@
+use crate::iterators::iterator_trait::MRBIterator;
+use crate::iterators::util_macros::delegate;
+use crate::iterators::util_macros::muncher;
+use crate::iterators::{ProdIter, UninitSlots};
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+#[cfg(doc)]
+use crate::ring_buffer::wrappers::uninit_rb::UninitRB;
+use core::mem::MaybeUninit;
+
+#[doc = r##"
+Producer obtained by splitting an [`UninitRB`].
+
+Unlike [`ProdIter`], this iterator only exposes operations which initialise the items they write:
+methods yielding references to the next items (e.g. [`ProdIter::get_next_item_mut`]) are not available,
+as such items are uninitialised.
+
+The normal producer can be obtained via [`Self::into_init`].
+"##]
+pub struct UninitProdIter<'buf, B: MutRB> {
+    inner: ProdIter<'buf, B>,
+}
+
+impl<'buf, B: MutRB<Item = T>, T> UninitProdIter<'buf, B> {
+    pub(crate) fn new(inner: ProdIter<'buf, B>) -> Self {
+        Self { inner }
+    }
+
+    /// Yields the underlying [`ProdIter`].
+    ///
+    /// The buffer keeps track of which items have been initialised, so this transition is always sound.
+    #[inline]
+    pub fn into_init(self) -> ProdIter<'buf, B> {
+        self.inner
+    }
+
+    fn inner(&self) -> &ProdIter<'buf, B> {
+        &self.inner
+    }
+    fn inner_mut(&mut self) -> &mut ProdIter<'buf, B> {
+        &mut self.inner
+    }
+
+    delegate!(MRBIterator (inline), pub fn available(&(mut) self) -> usize);
+    delegate!(MRBIterator (inline), pub fn wait_for(&(mut) self, count: usize));
+    delegate!(MRBIterator (inline), pub fn index(&self) -> usize);
+    delegate!(MRBIterator (inline), pub fn buf_len(&self) -> usize);
+    delegate!(MRBIterator (inline), pub fn alive_iters(&self) -> u8);
+    delegate!(MRBIterator (inline), pub fn prod_index(&self) -> usize);
+    delegate!(MRBIterator (inline), pub fn work_index(&self) -> usize);
+    delegate!(MRBIterator (inline), pub fn cons_index(&self) -> usize);
+    delegate!(MRBIterator (inline), pub fn position(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_pushed(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_worked(&self) -> u64);
+    delegate!(MRBIterator (inline), pub fn total_popped(&self) -> u64);
+
+    delegate!(ProdIter (inline), pub fn push(&(mut) self, value: T) -> Result<(), T>);
+
+    /// Same as [`ProdIter::push_slice`].
+    #[inline]
+    pub fn push_slice(&mut self, slice: &[T]) -> Option<()>
+    where
+        T: Copy,
+    {
+        self.inner.push_slice(slice)
+    }
+
+    /// Same as [`ProdIter::push_slice_clone`].
+    #[inline]
+    pub fn push_slice_clone(&mut self, slice: &[T]) -> Option<()>
+    where
+        T: Clone,
+    {
+        self.inner.push_slice_clone(slice)
+    }
+
+    /// Same as [`ProdIter::push_with`].
+    #[inline]
+    pub fn push_with<F>(&mut self, f: F) -> Result<(), F>
+    where
+        F: FnOnce(&mut MaybeUninit<T>) -> &mut T,
+    {
+        self.inner.push_with(f)
+    }
+
+    /// Same as [`ProdIter::reserve_uninit`].
+    #[inline]
+    pub fn reserve_uninit<'a>(&'a mut self, count: usize) -> Option<UninitSlots<'a, 'buf, B>> {
+        self.inner.reserve_uninit(count)
+    }
+}
@@ -1,9 +1,12 @@
-use crate::iterators::iterator_trait::{MRBIterator, PrivateMRBIterator};
-use crate::iterators::private_impl;
+use crate::iterators::iterator_trait::{
+    FollowerIter, MRBIterator, PrivateMRBIterator, Role, debug_iter,
+};
 #[allow(unused_imports)]
 use crate::iterators::sync_iterators::detached::Detached;
+use crate::iterators::{Frames, SlicePairMut, private_impl};
 use crate::ring_buffer::variants::ring_buffer_trait::{ConcurrentRB, IterManager, MutRB};
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
+use core::fmt;
 
 #[doc = r##"
 Iterator used to mutate elements in-place.
@@ -21,6 +24,7 @@ To avoid this [`Detached`] can be obtained by calling [`Self::detach`].
 pub struct WorkIter<'buf, B: MutRB> {
     pub(crate) index: usize,
     pub(crate) cached_avail: usize,
+    pub(crate) pos: u64,
     pub(crate) buffer: BufRef<'buf, B>,
 }
 
@@ -33,6 +37,8 @@ impl<B: MutRB + IterManager> Drop for WorkIter<'_, B> {
 }
 
 impl<B: MutRB<Item = T>, T> PrivateMRBIterator<T> for WorkIter<'_, B> {
+    const ROLE: Role = Role::Work;
+
     #[inline]
     fn _available(&mut self) -> usize {
         let succ_idx = self.succ_index();
@@ -67,20 +73,138 @@ impl<B: MutRB<Item = T>, T> MRBIterator for WorkIter<'_, B> {
     type Item = T;
 }
 
+impl<B: MutRB<Item = T>, T> fmt::Debug for WorkIter<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_iter(self, "WorkIter", f)
+    }
+}
+
+impl<B: MutRB<Item = T>, T> FollowerIter for WorkIter<'_, B> {}
+
 impl<'buf, B: MutRB<Item = T>, T> WorkIter<'buf, B> {
     pub(crate) fn new(value: BufRef<'buf, B>) -> WorkIter<'buf, B> {
+        let (cons, len) = (value.cons_index(), value.inner_len());
+        let dist = |index: usize| (index + len - cons) % len;
+
+        // A split without a worker does not move its index, which may then lie outside the stored items.
+        let mut index = value.work_index();
+        if dist(index) > dist(value.prod_index()) {
+            index = cons;
+            value.set_work_index(index);
+        }
+
         Self {
-            index: 0,
+            index,
+            // Positions count from the consumer, which starts at `0`.
+            pos: dist(index) as u64,
             buffer: value,
             cached_avail: 0,
         }
     }
 
+    /// Returns a [`StatsHandle`](crate::StatsHandle), reading the statistics of the buffer from any thread.
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    #[cfg(feature = "stats")]
+    pub fn stats_handle(&self) -> crate::StatsHandle<'buf, B> {
+        crate::StatsHandle::new(&self.buffer)
+    }
+
     /// Resets the index of the iterator. I.e., moves the iterator to the location occupied by its successor.
     #[inline]
     pub fn reset_index(&mut self) {
-        let new_idx = self.succ_index();
-        self.index = new_idx;
-        self.set_atomic_index(new_idx);
+        let count = self._available();
+        unsafe { self._advance(count) };
+    }
+
+    /// Returns a [`Frames`], yielding mutable references to frames of `N` items.
+    ///
+    /// # Panics
+    /// Panics if `N` is `0` or not lower than [`MRBIterator::buf_len`].
+    /// Unless the buffer is double mapped, also panics if neither the length of the buffer nor the index
+    /// of the iterator is a multiple of `N`.
+    pub fn frames<const N: usize>(&mut self) -> Frames<'_, Self, N> {
+        Frames::new(self)
+    }
+
+    /// Returns a [`Windows`], yielding overlapping windows of `size` items which advance by `hop` items.
+    ///
+    /// # Panics
+    /// Panics if `size` is `0` or not lower than [`MRBIterator::buf_len`], or if `hop` is `0` or greater than `size`.
+    pub fn windows(&mut self, size: usize, hop: usize) -> Windows<'_, 'buf, B> {
+        assert!(
+            size > 0 && size < self.buf_len(),
+            "window size must be between 1 and buf_len - 1"
+        );
+        assert!(
+            hop > 0 && hop <= size,
+            "hop must be between 1 and window size"
+        );
+
+        Windows {
+            iter: self,
+            size,
+            hop,
+            pending: false,
+        }
+    }
+}
+
+#[doc = r##"
+Sliding windows over the items available for a [`WorkIter`].
+
+Windows are `size` items long, and each one starts `hop` items after the previous one,
+so the last `size - hop` items of a window are the first ones of the next.
+These items are retained within the buffer, as the worker only advances by `hop`.
+
+This struct can only be created by calling [`WorkIter::windows`].
+
+A window can be split in two parts when crossing the end of the buffer; [`SlicePairMut`] handles this
+transparently. With double-mapped buffers (see [`crate::VmemStorage`]), windows are always contiguous.
+"##]
+pub struct Windows<'a, 'buf, B: MutRB> {
+    iter: &'a mut WorkIter<'buf, B>,
+    size: usize,
+    hop: usize,
+    pending: bool,
+}
+
+impl<B: MutRB> Drop for Windows<'_, '_, B> {
+    fn drop(&mut self) {
+        self.hop_pending();
+    }
+}
+
+impl<B: MutRB<Item = T>, T> Windows<'_, '_, B> {
+    /// Returns the next window, if there are enough available items.
+    ///
+    /// The worker is advanced by `hop` items before yielding a new window, so the previous one
+    /// is considered done. The same happens when [`Self`] is dropped.
+    pub fn next_window(&mut self) -> Option<SlicePairMut<'_, T>> {
+        self.hop_pending();
+
+        let ret = self.iter.get_workable_slice_exact(self.size)?;
+        self.pending = true;
+
+        Some(ret)
+    }
+
+    /// Returns the size of the windows.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of items windows are advanced by.
+    #[inline]
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    #[inline]
+    fn hop_pending(&mut self) {
+        if self.pending {
+            self.pending = false;
+            unsafe { self.iter.advance(self.hop) };
+        }
     }
 }
@@ -4,44 +4,74 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[doc(inline)]
-pub use iterators::MRBIterator;
+pub use iterators::{FollowerIter, MRBIterator};
 
 pub use ring_buffer::variants::ring_buffer_trait::{ConcurrentRB, MutRB};
+pub use ring_buffer::wrappers::uninit_rb::UninitRB;
 pub use ring_buffer::wrappers::unsafe_sync_cell::UnsafeSyncCell;
 
 #[cfg_attr(doc, doc(cfg(all(feature = "alloc", feature = "async"))))]
 #[cfg(all(feature = "async", feature = "alloc"))]
 pub use crate::ring_buffer::storage::heap::rb::AsyncHeapRB;
 
-#[cfg_attr(doc, doc(cfg(all(not(feature = "vmem"), feature = "async"))))]
-#[cfg(any(all(not(feature = "vmem"), feature = "async"), doc))]
+#[cfg_attr(doc, doc(cfg(feature = "async")))]
+#[cfg(any(feature = "async", doc))]
 pub use crate::ring_buffer::storage::stack::rb::AsyncStackRB;
 
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 #[cfg(feature = "alloc")]
 pub use crate::ring_buffer::storage::heap::{
     HeapSplit, HeapStorage,
-    rb::{ConcurrentHeapRB, LocalHeapRB},
+    rb::{BipHeapRB, ConcurrentHeapRB, LocalHeapRB},
 };
 
-#[cfg_attr(doc, doc(cfg(not(feature = "vmem"))))]
-#[cfg(any(not(feature = "vmem"), doc))]
 pub use crate::ring_buffer::storage::stack::{
     StackSplit, StackStorage,
-    rb::{ConcurrentStackRB, LocalStackRB},
+    rb::{BipStackRB, ConcurrentStackRB, LocalStackRB},
 };
 
+#[cfg_attr(doc, doc(cfg(all(feature = "vmem", feature = "async"))))]
+#[cfg(all(feature = "vmem", feature = "async"))]
+pub use crate::ring_buffer::storage::vmem::rb::AsyncVmemRB;
+
 #[cfg_attr(doc, doc(cfg(feature = "vmem")))]
-#[cfg(any(doc, feature = "vmem"))]
-pub use crate::ring_buffer::storage::heap::vmem_helper;
+#[cfg(feature = "vmem")]
+pub use crate::ring_buffer::storage::vmem::{
+    VmemStorage,
+    rb::{ConcurrentVmemRB, LocalVmemRB},
+    vmem_helper,
+};
 
+#[cfg_attr(
+    doc,
+    doc(cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        feature = "fd",
+        feature = "alloc"
+    )))
+)]
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    feature = "fd",
+    feature = "alloc"
+))]
+pub use crate::iterators::sync_iterators::datagram::DatagramRing;
+
+pub use crate::ring_buffer::state::{BufferState, OccupancyMap};
 pub use crate::ring_buffer::storage::Storage;
 
+#[cfg_attr(doc, doc(cfg(feature = "stats")))]
+#[cfg(feature = "stats")]
+pub use crate::ring_buffer::stats::{StatsHandle, StatsSnapshot};
+
 #[cfg_attr(doc, doc(cfg(feature = "async")))]
 #[cfg(feature = "async")]
 pub use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
+pub use crate::ring_buffer::variants::bip_rb::BipMutRingBuf;
 pub use crate::ring_buffer::variants::concurrent_rb::ConcurrentMutRingBuf;
 pub use crate::ring_buffer::variants::local_rb::LocalMutRingBuf;
 
@@ -1,3 +1,6 @@
+pub mod state;
+#[cfg(feature = "stats")]
+pub mod stats;
 pub mod storage;
 pub mod variants;
 pub mod wrappers;
@@ -0,0 +1,120 @@
+use crate::ring_buffer::variants::ring_buffer_trait::{IterManager, StorageManager};
+use core::fmt;
+
+/// State of a buffer, i.e. its indices, taken at a given moment.
+///
+/// Can be obtained from any iterator with [`crate::MRBIterator::buffer_state`].
+/// As indices are read one by one, they may be slightly out of sync with each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BufferState {
+    /// Length of the buffer.
+    pub buf_len: usize,
+    /// Number of consumed items which are kept readable.
+    pub history_len: usize,
+    /// Index of the producer.
+    pub prod_index: usize,
+    /// Index of the worker.
+    pub work_index: usize,
+    /// Index of the consumer.
+    pub cons_index: usize,
+}
+
+impl BufferState {
+    pub(crate) fn of<B: IterManager + StorageManager>(buf: &B) -> Self {
+        Self {
+            buf_len: buf.inner_len(),
+            history_len: buf.history_len(),
+            prod_index: buf.prod_index(),
+            work_index: buf.work_index(),
+            cons_index: buf.cons_index(),
+        }
+    }
+
+    #[inline]
+    fn dist(&self, from: usize, to: usize) -> usize {
+        (to + self.buf_len - from) % self.buf_len
+    }
+
+    /// Returns the number of items stored in the buffer, i.e. between the consumer and the producer.
+    #[inline]
+    pub fn occupied(&self) -> usize {
+        self.dist(self.cons_index, self.prod_index)
+    }
+
+    /// Returns the number of slots the producer can write to, i.e. [`MRBIterator::available`](crate::MRBIterator::available)
+    /// for the producer.
+    #[inline]
+    pub fn free(&self) -> usize {
+        (self.buf_len - 1 - self.occupied()).saturating_sub(self.history_len)
+    }
+
+    /// Returns how many items each stage is behind the previous one, i.e.:
+    /// * the number of items the worker has yet to process;
+    /// * the number of worked items the consumer has yet to consume.
+    ///
+    /// The returned value is only meaningful if the buffer has been split with a
+    /// [`WorkIter`](crate::iterators::WorkIter).
+    #[inline]
+    pub fn stage_lag(&self) -> (usize, usize) {
+        (
+            self.dist(self.work_index, self.prod_index),
+            self.dist(self.cons_index, self.work_index),
+        )
+    }
+
+    /// Returns an ASCII map of the buffer, made of `width` cells, to be printed with [`Display`](fmt::Display),
+    /// e.g. `[..##++....]`.
+    ///
+    /// Each cell stands for a range of slots, and is:
+    /// * `#`, if all the slots are occupied;
+    /// * `+`, if some of them are;
+    /// * `.`, if none is.
+    ///
+    /// `width` is capped to the length of the buffer.
+    pub fn occupancy_map(&self, width: usize) -> OccupancyMap {
+        OccupancyMap {
+            state: *self,
+            width: width.clamp(1, self.buf_len),
+        }
+    }
+}
+
+/// ASCII map of the occupancy of a buffer, returned by [`BufferState::occupancy_map`].
+#[derive(Clone, Copy, Debug)]
+pub struct OccupancyMap {
+    state: BufferState,
+    width: usize,
+}
+
+impl OccupancyMap {
+    /// Returns the number of occupied slots within `start..end`.
+    fn occupied_in(&self, start: usize, end: usize) -> usize {
+        let (len, cons) = (self.state.buf_len, self.state.cons_index);
+        let occupied_end = cons + self.state.occupied();
+        let overlap = |from: usize, to: usize| to.min(end).saturating_sub(from.max(start));
+
+        // Occupied slots wrap around the end of the buffer in the second range.
+        overlap(cons, occupied_end.min(len)) + overlap(0, occupied_end.saturating_sub(len))
+    }
+}
+
+impl fmt::Display for OccupancyMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.state.buf_len;
+
+        f.write_str("[")?;
+
+        for i in 0..self.width {
+            let (start, end) = (i * len / self.width, (i + 1) * len / self.width);
+
+            f.write_str(match self.occupied_in(start, end) {
+                0 => ".",
+                n if n == end - start => "#",
+                _ => "+",
+            })?;
+        }
+
+        f.write_str("]")
+    }
+}
@@ -0,0 +1,176 @@
+use crate::MutRB;
+use crate::iterators::iterator_trait::Role;
+use crate::ring_buffer::wrappers::buf_ref::BufRef;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::Relaxed;
+use crossbeam_utils::CachePadded;
+
+/// Counters of a single iterator.
+#[derive(Default)]
+struct StageStats {
+    moved: AtomicUsize,
+    failed: AtomicUsize,
+    wakeups: AtomicUsize,
+}
+
+/// Counters kept by each buffer, updated with relaxed atomic operations.
+///
+/// Counters of different iterators are kept on different cache lines, as each one is mostly
+/// updated by its own thread.
+#[derive(Default)]
+pub(crate) struct Stats {
+    high_water_mark: CachePadded<AtomicUsize>,
+    prod: CachePadded<StageStats>,
+    work: CachePadded<StageStats>,
+    cons: CachePadded<StageStats>,
+}
+
+/// Statistics as stored by buffers.
+///
+/// With `alloc` feature, they live in their own allocation, so that [`StatsHandle`]s can keep them
+/// alive without keeping the buffer alive.
+#[cfg(feature = "alloc")]
+pub(crate) type SharedStats = Arc<Stats>;
+#[cfg(not(feature = "alloc"))]
+pub(crate) type SharedStats = Stats;
+
+impl Stats {
+    #[inline]
+    fn stage(&self, role: Role) -> &StageStats {
+        match role {
+            Role::Prod => &self.prod,
+            Role::Work => &self.work,
+            Role::Cons => &self.cons,
+        }
+    }
+
+    /// Records that the iterator with the given role has moved by `count` items.
+    #[inline]
+    pub(crate) fn record_moved(&self, role: Role, count: usize) {
+        self.stage(role).moved.fetch_add(count, Relaxed);
+    }
+
+    /// Records that the iterator with the given role has not found enough available items.
+    #[inline]
+    pub(crate) fn record_failure(&self, role: Role) {
+        self.stage(role).failed.fetch_add(1, Relaxed);
+    }
+
+    /// Records that the task waiting on the iterator with the given role has been woken.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub(crate) fn record_wakeup(&self, role: Role) {
+        self.stage(role).wakeups.fetch_add(1, Relaxed);
+    }
+
+    /// Records the number of items stored in the buffer, after the producer has moved.
+    #[inline]
+    pub(crate) fn record_occupancy(&self, occupancy: usize) {
+        self.high_water_mark.fetch_max(occupancy, Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            high_water_mark: self.high_water_mark.load(Relaxed),
+            pushed: self.prod.moved.load(Relaxed),
+            worked: self.work.moved.load(Relaxed),
+            popped: self.cons.moved.load(Relaxed),
+            failed_pushes: self.prod.failed.load(Relaxed),
+            failed_works: self.work.failed.load(Relaxed),
+            failed_pops: self.cons.failed.load(Relaxed),
+            prod_wakeups: self.prod.wakeups.load(Relaxed),
+            work_wakeups: self.work.wakeups.load(Relaxed),
+            cons_wakeups: self.cons.wakeups.load(Relaxed),
+        }
+    }
+}
+
+/// Statistics of a buffer, taken at a given moment.
+///
+/// Counters start from `0` when the buffer is created and wrap around on overflow.
+/// There is no counter of overwritten items, as producers never overwrite items which have not been consumed.
+/// As they are read one by one, they may be slightly out of sync with each other.
+#[cfg_attr(doc, doc(cfg(feature = "stats")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StatsSnapshot {
+    /// Highest number of items stored in the buffer at once, i.e. between the consumer and the producer.
+    pub high_water_mark: usize,
+    /// Number of items the producer has moved past.
+    pub pushed: usize,
+    /// Number of items the worker has moved past.
+    pub worked: usize,
+    /// Number of items the consumer has moved past.
+    pub popped: usize,
+    /// Number of attempts of the producer which failed because the buffer was full.
+    pub failed_pushes: usize,
+    /// Number of attempts of the worker which failed because there were not enough items to work.
+    pub failed_works: usize,
+    /// Number of attempts of the consumer to pop items which failed because the buffer was empty.
+    /// Failed peeks are not counted.
+    pub failed_pops: usize,
+    /// Number of times a task waiting on the async producer has been woken.
+    pub prod_wakeups: usize,
+    /// Number of times a task waiting on the async worker has been woken.
+    pub work_wakeups: usize,
+    /// Number of times a task waiting on the async consumer has been woken.
+    pub cons_wakeups: usize,
+}
+
+#[doc = r##"
+Handle reading the statistics of a buffer, obtained with `stats_handle` on any iterator
+(e.g. [`crate::iterators::ProdIter::stats_handle`]).
+
+The handle only keeps the statistics alive, not the buffer, and can be sent to another thread,
+e.g. to feed a dashboard.
+"##]
+#[cfg_attr(doc, doc(cfg(feature = "stats")))]
+pub struct StatsHandle<'buf, B: MutRB> {
+    #[cfg(feature = "alloc")]
+    stats: SharedStats,
+    #[cfg(not(feature = "alloc"))]
+    stats: &'buf Stats,
+    _phantom: PhantomData<fn() -> &'buf B>,
+}
+
+impl<'buf, B: MutRB> StatsHandle<'buf, B> {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(buffer: &BufRef<'buf, B>) -> Self {
+        Self {
+            stats: buffer.stats().clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) fn new(buffer: &BufRef<'buf, B>) -> Self {
+        // Without `alloc`, buffers can only be borrowed, so they outlive `'buf`.
+        let stats = unsafe { &*(buffer.stats() as *const Stats) };
+
+        Self {
+            stats,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the current statistics of the buffer.
+    #[inline]
+    pub fn snapshot(&self) -> StatsSnapshot {
+        self.stats.snapshot()
+    }
+}
+
+impl<B: MutRB> Clone for StatsHandle<'_, B> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            stats: Arc::clone(&self.stats),
+            #[cfg(not(feature = "alloc"))]
+            stats: self.stats,
+            _phantom: PhantomData,
+        }
+    }
+}
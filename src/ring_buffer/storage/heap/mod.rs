@@ -1,7 +1,6 @@
 #![cfg(feature = "alloc")]
 
 pub mod rb;
-pub mod vmem_helper;
 
 use core::ops::Index;
 
@@ -20,38 +19,12 @@ pub struct HeapStorage<T> {
 impl<T> Drop for HeapStorage<T> {
     fn drop(&mut self) {
         unsafe {
-            #[cfg(feature = "vmem")]
-            {
-                let p = core::ptr::slice_from_raw_parts_mut(self.inner, self.len);
-                core::ptr::drop_in_place(p);
-
-                let size = self.len * size_of::<T>();
-
-                // glibc manual says that it is fine to unmap two mapped blocks at the same
-                // time. Nevertheless, I don't trust such a guarantee, as everything can change
-                // at any time, so the blocks get unmapped one after the other.
-                libc::munmap(self.inner.byte_add(size) as _, size);
-                libc::munmap(self.inner as _, size);
-            }
-
-            #[cfg(not(feature = "vmem"))]
             let _ = Box::from_raw(core::ptr::slice_from_raw_parts_mut(self.inner, self.len));
         }
     }
 }
 
 impl<T> HeapStorage<T> {
-    #[cfg(feature = "vmem")]
-    fn new(value: Box<[UnsafeSyncCell<T>]>) -> Self {
-        let r = vmem_helper::new(&value);
-
-        Self {
-            inner: r,
-            len: value.len(),
-        }
-    }
-
-    #[cfg(not(feature = "vmem"))]
     fn new(value: Box<[UnsafeSyncCell<T>]>) -> Self {
         let len = value.len();
 
@@ -158,9 +131,6 @@ pub mod test {
         use crate::{HeapStorage, UnsafeSyncCell};
         use alloc::vec;
 
-        #[cfg(feature = "vmem")]
-        let buf_len = crate::vmem_helper::page_size();
-        #[cfg(not(feature = "vmem"))]
         let buf_len = 100;
 
         let _ = HeapStorage::from(vec![0; buf_len]);
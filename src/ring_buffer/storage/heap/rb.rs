@@ -1,65 +1,49 @@
-use crate::HeapStorage;
-#[allow(unused_imports)]
-use crate::iterators::ProdIter;
+use crate::iterators::{ConsIter, UninitProdIter, WorkIter};
 #[cfg(any(feature = "async", doc))]
 use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
-use crate::{ConcurrentMutRingBuf, LocalMutRingBuf, UnsafeSyncCell};
+use crate::ring_buffer::variants::ring_buffer_trait::StorageManager;
+use crate::ring_buffer::wrappers::uninit_rb::impl_uninit_splits::impl_uninit_heap_splits;
+use crate::{BipMutRingBuf, ConcurrentMutRingBuf, LocalMutRingBuf, UnsafeSyncCell};
+use crate::{HeapSplit, HeapStorage, UninitRB};
 use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
 
-fn get_range_max<T>(capacity: usize) -> usize {
-    #[cfg(feature = "vmem")]
-    {
-        let min_size = capacity * size_of::<T>();
-        return super::vmem_helper::get_page_size_mul(min_size) / size_of::<T>();
-    }
-    #[cfg(not(feature = "vmem"))]
-    return capacity;
-}
-
 macro_rules! impl_rb {
     ($t: tt) => {
         impl<T> From<Vec<T>> for $t<T> {
             #[doc = concat!("Converts a `Vec<T>` into a [`", stringify!($t), "`].")]
             /// Note that the length of the buffer will be equal to the length of the vector, and *not*
             /// to its capacity.
-            /// # Behaviour with `vmem` feature
-            /// When `vmem` feature is enabled, the capacity of the buffer must be a multiple of
-            /// the system's page size, so must be the length of the passed `Vec`.
-            /// Please, use [`crate::vmem_helper::get_page_size_mul`] to get a suitable length.
+            ///
+            /// The buffer starts empty, so the elements of the vector are dropped right away: unlike in previous
+            /// versions, they can no longer be read or edited through the producer.
+            #[doc = concat!("To get an empty buffer, prefer [`", stringify!($t), "::new_zeroed`], which does not build them.")]
             fn from(value: Vec<T>) -> Self {
-                Self::_from(HeapStorage::from(value))
+                let ret = Self::_from(HeapStorage::from(value));
+                unsafe { ret.drop_range(0, ret.inner_len()) };
+                ret
             }
         }
 
         impl<T> $t<T> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
-            /// # Safety
-            /// The buffer must be then initialised using proper [`ProdIter`] methods (`*_init` ones).
-            /// # Behaviour with `vmem` feature
-            /// When `vmem` feature is enabled, the capacity of the buffer must be a multiple of
-            /// the system's page size.
-            /// This method accepts a minimum size, which will then be used to compute the actual
-            /// size (equal to or greater than it).
-            pub unsafe fn new_zeroed(capacity: usize) -> Self {
+            pub fn new_zeroed(capacity: usize) -> Self {
                 Self::_from(
                     HeapStorage::from(
-                        (0..get_range_max::<T>(capacity))
+                        (0..capacity)
                         .map(|_| UnsafeSyncCell::new_zeroed()).collect::<Box<[UnsafeSyncCell<T>]>>()
                     )
                 )
             }
 
-            #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and elements initialised to `default`.")]
-            /// # Behaviour with `vmem` feature
-            /// When `vmem` feature is enabled, the capacity of the buffer must be a multiple of
-            /// the system's page size.
-            /// This method accepts a minimum size, which will then be used to compute the actual
-            /// size (equal to or greater than it).
+            #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity.")]
+            ///
+            /// Elements are built with `T::default` and dropped right away, as the buffer starts empty.
+            #[deprecated(since = "1.0.0", note = "elements are dropped right away, as the buffer starts empty; use `new_zeroed` instead")]
             pub fn default(capacity: usize) -> Self
                 where T: Default + Clone {
-                Self::from(vec![T::default(); get_range_max::<T>(capacity)])
+                Self::from(vec![T::default(); capacity])
             }
         }
     };
@@ -77,6 +61,7 @@ impl_rb!(AsyncHeapRB);
 pub type ConcurrentHeapRB<T> = ConcurrentMutRingBuf<HeapStorage<T>>;
 
 impl_rb!(ConcurrentHeapRB);
+impl_uninit_heap_splits!(ConcurrentHeapRB);
 
 // Local
 
@@ -84,3 +69,11 @@ impl_rb!(ConcurrentHeapRB);
 pub type LocalHeapRB<T> = LocalMutRingBuf<HeapStorage<T>>;
 
 impl_rb!(LocalHeapRB);
+impl_uninit_heap_splits!(LocalHeapRB);
+
+// Bipartite
+
+/// A heap-allocated bipartite buffer, usable in a concurrent environment.
+pub type BipHeapRB<T> = BipMutRingBuf<HeapStorage<T>>;
+
+impl_rb!(BipHeapRB);
@@ -2,6 +2,7 @@ use crate::UnsafeSyncCell;
 
 pub mod heap;
 pub mod stack;
+pub mod vmem;
 
 pub(crate) trait MRBIndex<Idx: ?Sized> {
     type Output: ?Sized;
@@ -21,35 +22,47 @@ pub trait Storage: MRBIndex<usize, Output = UnsafeSyncCell<Self::Item>> {
     fn as_mut_ptr(&mut self) -> *mut UnsafeSyncCell<Self::Item>;
     /// Returns the length of the underlying array.
     fn len(&self) -> usize;
+    /// Returns `true` if the underlying array is mapped twice in a row, so that items can be accessed
+    /// past its end as if it were contiguous. See [`crate::VmemStorage`].
+    #[inline]
+    fn is_double_mapped(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) mod impl_splits {
     macro_rules! impl_splits {
         ($Struct: tt) => {
             #[cfg(feature = "alloc")]
-            impl<T> HeapSplit<$Struct<HeapStorage<T>>> for $Struct<HeapStorage<T>> {
-                fn split<'buf>(
-                    self,
+            impl_splits!(@heap $Struct, HeapStorage);
+            #[cfg(feature = "vmem")]
+            impl_splits!(@heap $Struct, VmemStorage);
+
+            impl<T, const N: usize> StackSplit<$Struct<StackStorage<T, N>>>
+                for $Struct<StackStorage<T, N>>
+            {
+                fn split(
+                    &'_ mut self,
                 ) -> (
-                    ProdIter<'buf, $Struct<HeapStorage<T>>>,
-                    ConsIter<'buf, $Struct<HeapStorage<T>>, false>,
+                    ProdIter<'_, $Struct<StackStorage<T, N>>>,
+                    ConsIter<'_, $Struct<StackStorage<T, N>>, false>,
                 ) {
                     self.set_alive_iters(2);
 
-                    let r = BufRef::new(self);
+                    let r = BufRef::from_ref(self);
                     (ProdIter::new(r.clone()), ConsIter::new(r))
                 }
 
-                fn split_mut<'buf>(
-                    self,
+                fn split_mut(
+                    &'_ mut self,
                 ) -> (
-                    ProdIter<'buf, $Struct<HeapStorage<T>>>,
-                    WorkIter<'buf, $Struct<HeapStorage<T>>>,
-                    ConsIter<'buf, $Struct<HeapStorage<T>>, true>,
+                    ProdIter<'_, $Struct<StackStorage<T, N>>>,
+                    WorkIter<'_, $Struct<StackStorage<T, N>>>,
+                    ConsIter<'_, $Struct<StackStorage<T, N>>, true>,
                 ) {
                     self.set_alive_iters(3);
 
-                    let r = BufRef::new(self);
+                    let r = BufRef::from_ref(self);
                     (
                         ProdIter::new(r.clone()),
                         WorkIter::new(r.clone()),
@@ -57,33 +70,31 @@ pub(crate) mod impl_splits {
                     )
                 }
             }
-
-            #[cfg(not(feature = "vmem"))]
-            impl<T, const N: usize> StackSplit<$Struct<StackStorage<T, N>>>
-                for $Struct<StackStorage<T, N>>
-            {
-                fn split(
-                    &'_ mut self,
+        };
+        (@heap $Struct: tt, $Storage: tt) => {
+            impl<T> HeapSplit<$Struct<$Storage<T>>> for $Struct<$Storage<T>> {
+                fn split<'buf>(
+                    self,
                 ) -> (
-                    ProdIter<'_, $Struct<StackStorage<T, N>>>,
-                    ConsIter<'_, $Struct<StackStorage<T, N>>, false>,
+                    ProdIter<'buf, $Struct<$Storage<T>>>,
+                    ConsIter<'buf, $Struct<$Storage<T>>, false>,
                 ) {
                     self.set_alive_iters(2);
 
-                    let r = BufRef::from_ref(self);
+                    let r = BufRef::new(self);
                     (ProdIter::new(r.clone()), ConsIter::new(r))
                 }
 
-                fn split_mut(
-                    &'_ mut self,
+                fn split_mut<'buf>(
+                    self,
                 ) -> (
-                    ProdIter<'_, $Struct<StackStorage<T, N>>>,
-                    WorkIter<'_, $Struct<StackStorage<T, N>>>,
-                    ConsIter<'_, $Struct<StackStorage<T, N>>, true>,
+                    ProdIter<'buf, $Struct<$Storage<T>>>,
+                    WorkIter<'buf, $Struct<$Storage<T>>>,
+                    ConsIter<'buf, $Struct<$Storage<T>>, true>,
                 ) {
                     self.set_alive_iters(3);
 
-                    let r = BufRef::from_ref(self);
+                    let r = BufRef::new(self);
                     (
                         ProdIter::new(r.clone()),
                         WorkIter::new(r.clone()),
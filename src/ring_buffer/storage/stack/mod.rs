@@ -1,6 +1,3 @@
-#![cfg_attr(doc, doc(cfg(not(feature = "vmem"))))]
-#![cfg(any(not(feature = "vmem"), doc))]
-
 pub mod rb;
 
 use core::ops::Index;
@@ -1,24 +1,31 @@
-#[cfg(doc)]
-use crate::iterators::ProdIter;
-
+use crate::iterators::{ConsIter, UninitProdIter, WorkIter};
 #[cfg(any(feature = "async", doc))]
 use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
-use crate::{ConcurrentMutRingBuf, LocalMutRingBuf, StackStorage, UnsafeSyncCell};
+use crate::ring_buffer::variants::ring_buffer_trait::StorageManager;
+use crate::ring_buffer::wrappers::uninit_rb::impl_uninit_splits::impl_uninit_stack_splits;
+use crate::{
+    BipMutRingBuf, ConcurrentMutRingBuf, LocalMutRingBuf, StackSplit, StackStorage, UninitRB,
+    UnsafeSyncCell,
+};
 
 macro_rules! impl_rb {
     ($t: tt) => {
         impl<T, const N: usize> From<[T; N]> for $t<T, N> {
             #[doc = concat!("Converts an array into a [`", stringify!($t), "`].")]
+            ///
+            /// The buffer starts empty, so the elements of the array are dropped right away: unlike in previous
+            /// versions, they can no longer be read or edited through the producer.
+            #[doc = concat!("To get an empty buffer, prefer [`", stringify!($t), "::new_zeroed`], which does not build them.")]
             fn from(value: [T; N]) -> Self {
-                Self::_from(StackStorage::from(value))
+                let ret = Self::_from(StackStorage::from(value));
+                unsafe { ret.drop_range(0, ret.inner_len()) };
+                ret
             }
         }
 
         impl<T, const N: usize> $t<T, N> {
             #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
-            /// # Safety
-            /// The buffer must be then initialised using proper [`ProdIter`] methods (`*_init` ones).
-            pub unsafe fn new_zeroed() -> Self {
+            pub fn new_zeroed() -> Self {
                 let v: [UnsafeSyncCell<T>; N] = core::array::from_fn(|_| UnsafeSyncCell::new_zeroed());
 
                 Self::_from(StackStorage::from(v))
@@ -26,7 +33,10 @@ macro_rules! impl_rb {
         }
 
         impl<T: Default + Copy, const N: usize> Default for $t<T, N> {
-            #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and elements initialised to `default`.")]
+            #[doc = concat!("Creates a new, empty [`", stringify!($t), "`].")]
+            ///
+            /// Elements are built with `T::default` and dropped right away, as the buffer starts empty.
+            #[doc = concat!("Prefer [`", stringify!($t), "::new_zeroed`], which does not build them.")]
             fn default() -> Self {
                 Self::from([T::default(); N])
             }
@@ -44,8 +54,15 @@ impl_rb!(AsyncStackRB);
 pub type ConcurrentStackRB<T, const N: usize> = ConcurrentMutRingBuf<StackStorage<T, N>>;
 
 impl_rb!(ConcurrentStackRB);
+impl_uninit_stack_splits!(ConcurrentStackRB);
 
 /// A stack-allocated ring buffer usable in local environment.
 pub type LocalStackRB<T, const N: usize> = LocalMutRingBuf<StackStorage<T, N>>;
 
 impl_rb!(LocalStackRB);
+impl_uninit_stack_splits!(LocalStackRB);
+
+/// A stack-allocated bipartite buffer, usable in a concurrent environment.
+pub type BipStackRB<T, const N: usize> = BipMutRingBuf<StackStorage<T, N>>;
+
+impl_rb!(BipStackRB);
@@ -0,0 +1,172 @@
+#![cfg_attr(doc, doc(cfg(feature = "vmem")))]
+#![cfg(feature = "vmem")]
+
+pub mod rb;
+pub mod vmem_helper;
+
+use core::mem::ManuallyDrop;
+use core::ops::Index;
+use core::ptr;
+
+use crate::UnsafeSyncCell;
+use crate::ring_buffer::storage::{MRBIndex, Storage};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[doc = r##"
+Heap-allocated storage, mapped twice in a row in virtual memory.
+
+Thanks to the double mapping, items can be accessed past the end of the storage as if it were contiguous,
+so slices yielded by iterators never wrap around. For more information, see the `vmem` section in the README.
+
+Any length is accepted and kept as given. However, the end of the storage meets its mirrored beginning only if its
+size (length times the size of the stored type) is a multiple of the system's page size: otherwise, the storage is
+not mapped and falls back to a plain heap allocation, as it does when the mapping fails. A suitable length can be
+obtained via [`vmem_helper::get_page_len_mul`]. Whether the mapping has been established can be checked via
+[`Self::is_mapped`].
+"##]
+pub struct VmemStorage<T> {
+    inner: *mut UnsafeSyncCell<T>,
+    len: usize,
+    mapped: bool,
+}
+
+impl<T> Drop for VmemStorage<T> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.mapped {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.inner, self.len));
+                vmem_helper::free(self.inner, self.len);
+            } else {
+                let _ = Box::from_raw(ptr::slice_from_raw_parts_mut(self.inner, self.len));
+            }
+        }
+    }
+}
+
+impl<T> VmemStorage<T> {
+    fn new(value: Box<[UnsafeSyncCell<T>]>) -> Self {
+        let len = value.len();
+        // The mirror would not meet the end of the storage, so it is not worth mapping.
+        let mirrored = len > 0 && vmem_helper::get_page_len_mul::<T>(len) == len;
+
+        let Some(r) = mirrored.then(|| vmem_helper::new::<T>(len)).flatten() else {
+            let v = Box::into_raw(value);
+
+            return Self {
+                inner: unsafe { (*v).as_mut_ptr() },
+                len,
+                mapped: false,
+            };
+        };
+
+        // Items are moved into the mapped region, so only the allocation has to be freed.
+        let value = unsafe {
+            Box::from_raw(Box::into_raw(value) as *mut [ManuallyDrop<UnsafeSyncCell<T>>])
+        };
+        unsafe { ptr::copy_nonoverlapping(value.as_ptr() as *const UnsafeSyncCell<T>, r, len) };
+
+        Self {
+            inner: r,
+            len,
+            mapped: true,
+        }
+    }
+
+    /// Returns `true` if the storage is mapped twice in a row, `false` if it fell back to a plain heap allocation.
+    #[inline]
+    pub fn is_mapped(&self) -> bool {
+        self.mapped
+    }
+}
+
+impl<T> From<Box<[T]>> for VmemStorage<T> {
+    fn from(value: Box<[T]>) -> Self {
+        Self::new(unsafe { core::mem::transmute::<Box<[T]>, Box<[UnsafeSyncCell<T>]>>(value) })
+    }
+}
+
+impl<T> From<Box<[UnsafeSyncCell<T>]>> for VmemStorage<T> {
+    fn from(value: Box<[UnsafeSyncCell<T>]>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Vec<T>> for VmemStorage<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from(value.into_boxed_slice())
+    }
+}
+
+impl<T> From<Vec<UnsafeSyncCell<T>>> for VmemStorage<T> {
+    fn from(value: Vec<UnsafeSyncCell<T>>) -> Self {
+        Self::new(value.into_boxed_slice())
+    }
+}
+
+impl<T> Index<usize> for VmemStorage<T> {
+    type Output = UnsafeSyncCell<T>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {index}",
+            self.len
+        );
+        unsafe { &*self.inner.add(index) }
+    }
+}
+
+impl<T> MRBIndex<usize> for VmemStorage<T> {
+    type Output = UnsafeSyncCell<T>;
+
+    #[inline]
+    fn _index(&self, index: usize) -> &Self::Output {
+        unsafe { &*self.inner.add(index) }
+    }
+}
+
+impl<T> Storage for VmemStorage<T> {
+    type Item = T;
+
+    #[inline]
+    fn as_ptr(&self) -> *const UnsafeSyncCell<Self::Item> {
+        self.inner as _
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut UnsafeSyncCell<Self::Item> {
+        self.inner
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn is_double_mapped(&self) -> bool {
+        self.mapped
+    }
+}
+
+pub mod test {
+    #[test]
+    fn from_tests() {
+        use crate::{UnsafeSyncCell, VmemStorage};
+        use alloc::vec;
+
+        let buf_len = crate::vmem_helper::page_size();
+
+        assert!(VmemStorage::from(vec![0u8; buf_len]).is_mapped());
+        assert!(VmemStorage::from(vec![0u8; buf_len].into_boxed_slice()).is_mapped());
+        let _: VmemStorage<i32> = VmemStorage::from(vec![UnsafeSyncCell::new(0i32); buf_len]);
+        let _: VmemStorage<i32> =
+            VmemStorage::from(vec![UnsafeSyncCell::new(0i32); buf_len].into_boxed_slice());
+
+        // Not a multiple of the page size: the length is kept, and the storage is not mapped.
+        let s = VmemStorage::from(vec![0u8; buf_len + 1]);
+        assert!(!s.is_mapped());
+        assert_eq!(crate::Storage::len(&s), buf_len + 1);
+    }
+}
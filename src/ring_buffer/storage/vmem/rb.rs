@@ -0,0 +1,88 @@
+use crate::iterators::{ConsIter, UninitProdIter, WorkIter};
+#[cfg(feature = "async")]
+use crate::ring_buffer::variants::async_rb::AsyncMutRingBuf;
+use crate::ring_buffer::variants::ring_buffer_trait::StorageManager;
+use crate::ring_buffer::wrappers::uninit_rb::impl_uninit_splits::impl_uninit_heap_splits;
+use crate::{ConcurrentMutRingBuf, LocalMutRingBuf, UnsafeSyncCell};
+use crate::{HeapSplit, UninitRB, VmemStorage};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+macro_rules! impl_rb {
+    ($t: tt) => {
+        impl<T> From<Vec<T>> for $t<T> {
+            #[doc = concat!("Converts a `Vec<T>` into a [`", stringify!($t), "`].")]
+            /// Note that the length of the buffer is based on the length of the vector, and *not*
+            /// on its capacity.
+            ///
+            /// The buffer is double mapped only if its size is a multiple of the system's page size.
+            /// See [`crate::vmem_helper::get_page_len_mul`].
+            ///
+            /// The buffer starts empty, so the elements of the vector are dropped right away: unlike in previous
+            /// versions, they can no longer be read or edited through the producer.
+            #[doc = concat!("To get an empty buffer, prefer [`", stringify!($t), "::new_zeroed`], which does not build them.")]
+            fn from(value: Vec<T>) -> Self {
+                let len = value.len();
+                let ret = Self::_from(VmemStorage::from(value));
+                unsafe { ret.drop_range(0, len) };
+                ret
+            }
+        }
+
+        impl<T> $t<T> {
+            #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity and zeroed (uninitialised) elements.")]
+            ///
+            /// The buffer is double mapped only if its size is a multiple of the system's page size.
+            /// See [`crate::vmem_helper::get_page_len_mul`].
+            pub fn new_zeroed(capacity: usize) -> Self {
+                Self::_from(
+                    VmemStorage::from(
+                        (0..capacity).map(|_| UnsafeSyncCell::new_zeroed()).collect::<Box<[UnsafeSyncCell<T>]>>()
+                    )
+                )
+            }
+
+            #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity.")]
+            ///
+            /// The buffer is double mapped only if its size is a multiple of the system's page size.
+            /// See [`crate::vmem_helper::get_page_len_mul`].
+            ///
+            /// Elements are built with `T::default` and dropped right away, as the buffer starts empty.
+            #[deprecated(since = "1.0.0", note = "elements are dropped right away, as the buffer starts empty; use `new_zeroed` instead")]
+            pub fn default(capacity: usize) -> Self
+                where T: Default + Clone {
+                Self::from(vec![T::default(); capacity])
+            }
+
+            /// Returns `true` if the buffer is mapped twice in a row, `false` if it fell back
+            /// to a plain heap allocation. See [`VmemStorage`].
+            #[inline]
+            pub fn is_mapped(&self) -> bool {
+                self.inner().is_mapped()
+            }
+        }
+    };
+}
+
+// Concurrent
+
+/// A double-mapped asynchronous ring buffer usable in concurrent environment.
+#[cfg(feature = "async")]
+pub type AsyncVmemRB<T> = AsyncMutRingBuf<VmemStorage<T>>;
+#[cfg(feature = "async")]
+impl_rb!(AsyncVmemRB);
+
+/// A double-mapped ring buffer usable in a concurrent environment.
+pub type ConcurrentVmemRB<T> = ConcurrentMutRingBuf<VmemStorage<T>>;
+
+impl_rb!(ConcurrentVmemRB);
+impl_uninit_heap_splits!(ConcurrentVmemRB);
+
+// Local
+
+/// A double-mapped ring buffer usable in a local environment.
+pub type LocalVmemRB<T> = LocalMutRingBuf<VmemStorage<T>>;
+
+impl_rb!(LocalVmemRB);
+impl_uninit_heap_splits!(LocalVmemRB);
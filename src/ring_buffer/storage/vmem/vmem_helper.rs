@@ -0,0 +1,165 @@
+//! Utilities for `vmem` optimisation.
+
+use crate::UnsafeSyncCell;
+use core::ptr;
+use libc::c_int;
+
+/// Returns the page size in use by the system.
+#[cfg(unix)]
+pub fn page_size() -> usize {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+/// Returns a multiple of the page size in use by the system.
+#[cfg(unix)]
+pub fn get_page_size_mul(min_size: usize) -> usize {
+    let page_size = page_size();
+    min_size.div_ceil(page_size) * page_size
+}
+
+/// Returns the lowest length, not lower than `min_len`, such that the size of a buffer holding items of type `T`
+/// is a multiple of the page size in use by the system.
+///
+/// Only `vmem` buffers of such a length are double mapped, so that they are contiguous across their end.
+#[cfg(unix)]
+pub fn get_page_len_mul<T>(min_len: usize) -> usize {
+    let page_size = page_size();
+
+    match size_of::<T>() {
+        0 => min_len,
+        size => {
+            let step = page_size / gcd(page_size, size);
+            min_len.max(1).div_ceil(step) * step
+        }
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+unsafe fn open_fd() -> c_int {
+    use alloc::ffi::CString;
+    use alloc::format;
+    use libc::rand;
+
+    let group_name = option_env!("IOS_APP_GROUP_NAME");
+
+    libc::srand(libc::time(ptr::null_mut()) as libc::c_uint);
+    let mut name;
+
+    let fd = loop {
+        name = CString::new(if let Some(gn) = group_name {
+            format!("{}{}{}", gn, "/mrb", rand() % 99)
+        } else {
+            format!("{}{}", "/mrb", rand() % 99)
+        })
+        .unwrap();
+        let fd = libc::shm_open(
+            name.as_ptr(),
+            libc::O_CREAT | libc::O_RDWR | libc::O_EXCL,
+            0700,
+        );
+
+        if fd != -1 || *libc::__error() != libc::EEXIST {
+            break fd;
+        }
+    };
+
+    assert_eq!(libc::shm_unlink(name.as_ptr()), 0, "shm_unlink failed");
+
+    fd
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+unsafe fn open_fd() -> c_int {
+    unsafe { libc::memfd_create(c"/mrb".as_ptr(), 0) }
+}
+
+/// Maps a region able to hold `len` items twice in a row, returning a pointer to its beginning.
+/// The size of the region is rounded up to a multiple of the page size.
+///
+/// Returns `None` if the mapping fails. Nothing is left allocated in that case.
+pub(crate) fn new<T>(len: usize) -> Option<*mut UnsafeSyncCell<T>> {
+    let size = get_page_size_mul(len * size_of::<T>());
+
+    if size == 0 {
+        return None;
+    }
+
+    unsafe {
+        // The real place where the buffer is allocated
+        let fd = open_fd();
+
+        if fd == -1 {
+            return None;
+        }
+
+        let buffer = map(fd, size);
+        libc::close(fd);
+
+        buffer.map(|b| b as *mut UnsafeSyncCell<T>)
+    }
+}
+
+/// Maps `fd` twice in a row, returning a pointer to the beginning of the region.
+unsafe fn map(fd: c_int, size: usize) -> Option<*mut libc::c_void> {
+    unsafe {
+        if libc::ftruncate(fd, size as libc::off_t) == -1 {
+            return None;
+        }
+
+        // Reserve a block double the size of the buffer
+        let buffer = libc::mmap(
+            ptr::null_mut(),
+            2 * size as libc::size_t,
+            libc::PROT_NONE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if buffer == libc::MAP_FAILED {
+            return None;
+        }
+
+        // Map both parts of the previously reserved memory to the fd.
+        // Regarding the reserved memory, the overlapping part is automatically unmapped.
+        for offset in [0, size] {
+            let addr = libc::mmap(
+                buffer.byte_add(offset),
+                size as libc::size_t,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED | libc::MAP_FIXED,
+                fd,
+                0,
+            );
+
+            if addr == libc::MAP_FAILED {
+                libc::munmap(buffer, 2 * size);
+                return None;
+            }
+        }
+
+        Some(buffer)
+    }
+}
+
+/// Unmaps a region previously mapped with [`new`].
+///
+/// # Safety
+/// `ptr` must have been returned by [`new`] with the same `len`, and must not be used anymore.
+pub(crate) unsafe fn free<T>(ptr: *mut UnsafeSyncCell<T>, len: usize) {
+    let size = get_page_size_mul(len * size_of::<T>());
+
+    // glibc manual says that it is fine to unmap two mapped blocks at the same
+    // time. Nevertheless, I don't trust such a guarantee, as everything can change
+    // at any time, so the blocks get unmapped one after the other.
+    unsafe {
+        libc::munmap(ptr.byte_add(size) as _, size);
+        libc::munmap(ptr as _, size);
+    }
+}
@@ -2,18 +2,22 @@
 #![cfg(any(feature = "async", doc))]
 
 use core::cell::UnsafeCell;
+use core::fmt;
 use core::num::NonZeroUsize;
 use core::sync::atomic::AtomicU8;
 use core::sync::atomic::AtomicUsize;
 use core::sync::atomic::Ordering::{Acquire, Release};
 
+use crate::iterators::iterator_trait::Role;
 use crate::iterators::{
     AsyncConsIter, AsyncProdIter, AsyncWorkIter, async_iterators::AsyncIterator,
 };
 use crate::iterators::{ConsIter, ProdIter, WorkIter};
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::SharedStats;
 use crate::ring_buffer::storage::Storage;
 use crate::ring_buffer::variants::ring_buffer_trait::{
-    ConcurrentRB, IterManager, MutRB, PrivateIterManager, StorageManager,
+    ConcurrentRB, IterManager, MutRB, PrivateIterManager, StorageManager, debug_buffer,
 };
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
 use crossbeam_utils::CachePadded;
@@ -26,6 +30,7 @@ use futures::task::AtomicWaker;
 pub struct AsyncMutRingBuf<S: Storage> {
     inner_len: NonZeroUsize,
     inner: UnsafeCell<S>,
+    history: usize,
 
     prod_idx: CachePadded<AtomicUsize>,
     work_idx: CachePadded<AtomicUsize>,
@@ -36,6 +41,9 @@ pub struct AsyncMutRingBuf<S: Storage> {
     pub(crate) cons_waker: CachePadded<AtomicWaker>,
 
     alive_iters: AtomicU8,
+
+    #[cfg(feature = "stats")]
+    stats: SharedStats,
 }
 
 impl<S: Storage<Item = T>, T> MutRB for AsyncMutRingBuf<S> {
@@ -123,6 +131,7 @@ impl<'buf, S: Storage<Item = T> + 'buf, T> AsyncMutRingBuf<S> {
         AsyncMutRingBuf {
             inner_len: NonZeroUsize::new(value.len()).unwrap(),
             inner: value.into(),
+            history: 0,
 
             prod_idx: CachePadded::new(0.into()),
             work_idx: CachePadded::new(0.into()),
@@ -133,10 +142,65 @@ impl<'buf, S: Storage<Item = T> + 'buf, T> AsyncMutRingBuf<S> {
             cons_waker: CachePadded::new(AtomicWaker::new()),
 
             alive_iters: AtomicU8::default(),
+
+            #[cfg(feature = "stats")]
+            stats: SharedStats::default(),
         }
     }
 }
 
+impl<S: Storage> AsyncMutRingBuf<S> {
+    /// Wakes the task waiting on the iterator with the given role, if there is one.
+    #[inline]
+    pub(crate) fn wake(&self, role: Role) {
+        let waker = match role {
+            Role::Prod => &self.prod_waker,
+            Role::Work => &self.work_waker,
+            Role::Cons => &self.cons_waker,
+        };
+
+        if let Some(waker) = waker.take() {
+            #[cfg(feature = "stats")]
+            self.stats.record_wakeup(role);
+
+            waker.wake();
+        }
+    }
+}
+
+impl<S: Storage<Item = T>, T: Copy> AsyncMutRingBuf<S> {
+    /// Keeps the last `k` consumed items readable through [`ConsIter::history`].
+    ///
+    /// The producer treats these items as still occupied, so the capacity of the buffer is reduced by `k`.
+    ///
+    /// Only buffers holding `Copy` items support this: consumed items are moved out of the buffer,
+    /// and only `Copy` ones stay valid to read once moved.
+    ///
+    /// # Panics
+    /// Panics if no room is left for new items, i.e. if `k` is greater than the length of the buffer minus `2`.
+    pub fn with_history(mut self, k: usize) -> Self {
+        assert!(
+            k + 2 <= self.inner_len.get(),
+            "history must leave room for at least one item"
+        );
+
+        self.history = k;
+        self
+    }
+}
+
+impl<S: Storage> fmt::Debug for AsyncMutRingBuf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_buffer(self, "AsyncMutRingBuf", f)
+    }
+}
+
+impl<S: Storage> Drop for AsyncMutRingBuf<S> {
+    fn drop(&mut self) {
+        self.drop_alive();
+    }
+}
+
 impl<S: Storage> PrivateIterManager for AsyncMutRingBuf<S> {
     fn set_alive_iters(&self, count: u8) {
         self.alive_iters.store(count, Release);
@@ -147,6 +211,12 @@ impl<S: Storage> PrivateIterManager for AsyncMutRingBuf<S> {
         self.alive_iters.fetch_sub(1, Release)
     }
 
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    fn stats(&self) -> &SharedStats {
+        &self.stats
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -213,4 +283,9 @@ impl<S: Storage<Item = T>, T> StorageManager for AsyncMutRingBuf<S> {
     fn inner_len(&self) -> usize {
         self.inner_len.get()
     }
+
+    #[inline]
+    fn history_len(&self) -> usize {
+        self.history
+    }
 }
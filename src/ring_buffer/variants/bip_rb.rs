@@ -0,0 +1,201 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::{Acquire, Release};
+
+use crate::iterators::{BipConsIter, BipProdIter};
+#[cfg(doc)]
+use crate::iterators::{ConsIter, ProdIter, SlicePair};
+use crate::ring_buffer::storage::Storage;
+use crate::ring_buffer::variants::ring_buffer_trait::StorageManager;
+use crossbeam_utils::CachePadded;
+
+#[doc = r##"
+Bipartite buffer, usable in a concurrent environment. This buffer is useful for implementing types.
+For more direct usage, consider using one of the following alternatives:
+- [`crate::BipHeapRB`]
+- [`crate::BipStackRB`].
+
+Unlike other buffers, both write and read regions are always contiguous, without the need for the `vmem` feature.
+When there is not enough room at the end of the buffer, the producer restarts from its beginning, and a
+watermark marks where valid data end, so that the consumer also restarts from the beginning once it reaches it.
+
+The price is that space left after the watermark is not usable until the consumer restarts,
+so a reservation may fail even if the total free space would be enough.
+
+This buffer can only be split into a producer ([`BipProdIter`]) and a consumer ([`BipConsIter`]).
+
+These are separate types rather than [`ProdIter`] and [`ConsIter`], as the latter assume that items follow
+each other modulo the length of the buffer: they compute available items from two indices and hand out
+[`SlicePair`]s wrapping around the end. Here, instead, the consumer has to jump from the watermark back to
+the beginning, and the producer may skip the tail of the buffer, so every operation depends on the watermark.
+Teaching the common iterators about it would put a watermark check on the hot path of every other buffer.
+As a consequence, bipartite iterators expose a smaller, reservation-based API: they cannot be made async
+nor detached, and they do not report statistics.
+"##]
+pub struct BipMutRingBuf<S: Storage> {
+    inner_len: NonZeroUsize,
+    inner: UnsafeCell<S>,
+
+    write_idx: CachePadded<AtomicUsize>,
+    read_idx: CachePadded<AtomicUsize>,
+    watermark: CachePadded<AtomicUsize>,
+}
+
+unsafe impl<S: Storage<Item = T>, T: Send> Sync for BipMutRingBuf<S> {}
+
+impl<S: Storage> fmt::Debug for BipMutRingBuf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BipMutRingBuf")
+            .field("len", &self.inner_len.get())
+            .field("write_index", &self.write_idx.load(Acquire))
+            .field("read_index", &self.read_idx.load(Acquire))
+            .field("watermark", &self.watermark.load(Acquire))
+            .finish()
+    }
+}
+
+impl<S: Storage<Item = T>, T> BipMutRingBuf<S> {
+    pub(crate) fn _from(value: S) -> BipMutRingBuf<S> {
+        assert!(value.len() > 0);
+
+        BipMutRingBuf {
+            inner_len: NonZeroUsize::new(value.len()).unwrap(),
+            inner: value.into(),
+
+            write_idx: CachePadded::new(0.into()),
+            read_idx: CachePadded::new(0.into()),
+            watermark: CachePadded::new(0.into()),
+        }
+    }
+
+    /// Borrows the buffer, yielding two iterators. See:
+    /// - [`BipProdIter`];
+    /// - [`BipConsIter`].
+    pub fn split(&mut self) -> (BipProdIter<'_, S>, BipConsIter<'_, S>) {
+        (BipProdIter::new(self), BipConsIter::new(self))
+    }
+
+    #[inline]
+    pub(crate) fn write_index(&self) -> usize {
+        self.write_idx.load(Acquire)
+    }
+
+    #[inline]
+    pub(crate) fn read_index(&self) -> usize {
+        self.read_idx.load(Acquire)
+    }
+
+    #[inline]
+    pub(crate) fn set_read_index(&self, index: usize) {
+        self.read_idx.store(index, Release);
+    }
+
+    /// Returns where the region to be reserved by the producer starts, if `count` contiguous
+    /// locations are free.
+    pub(crate) fn reserve_start(&self, count: usize) -> Option<usize> {
+        let (write, read, len) = (self.write_index(), self.read_index(), self.inner_len());
+
+        match write < read {
+            // The producer has already restarted: it cannot reach the consumer.
+            true => (write + count < read).then_some(write),
+            false if write + count <= len => Some(write),
+            // Restart from the beginning, if there is room before the consumer.
+            false => (count < read).then_some(0),
+        }
+    }
+
+    /// Returns the largest number of contiguous locations which can be reserved by the producer.
+    pub(crate) fn max_reservable(&self) -> usize {
+        let (write, read, len) = (self.write_index(), self.read_index(), self.inner_len());
+
+        match write < read {
+            true => read - write - 1,
+            false => (len - write).max(read.saturating_sub(1)),
+        }
+    }
+
+    /// Makes `count` items starting from `start` visible to the consumer.
+    pub(crate) fn commit(&self, start: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let (write, len) = (self.write_index(), self.inner_len());
+        let new_write = start + count;
+
+        if new_write < write && write != len {
+            // The producer restarted: valid data end where it stopped.
+            self.watermark.store(write, Release);
+        } else if new_write > self.watermark.load(Acquire) {
+            self.watermark.store(len, Release);
+        }
+
+        self.write_idx.store(new_write, Release);
+    }
+
+    /// Returns the readable region as `(start, len)`, restarting the consumer from the beginning
+    /// if it reached the watermark.
+    pub(crate) fn readable(&self) -> (usize, usize) {
+        let write = self.write_index();
+        let watermark = self.watermark.load(Acquire);
+        let mut read = self.read_index();
+
+        if read == watermark && write < read {
+            read = 0;
+            self.set_read_index(0);
+        }
+
+        let end = if write < read { watermark } else { write };
+        (read, end - read)
+    }
+
+    /// Returns a pointer to the location at `index`.
+    #[inline]
+    pub(crate) fn ptr_at(&self, index: usize) -> *mut T {
+        unsafe { (*self.inner.get()).as_mut_ptr().add(index) as *mut T }
+    }
+}
+
+impl<S: Storage> Drop for BipMutRingBuf<S> {
+    fn drop(&mut self) {
+        let (write, read) = (self.write_index(), self.read_index());
+
+        unsafe {
+            if write < read {
+                let watermark = self.watermark.load(Acquire);
+
+                self.drop_range(read, watermark - read);
+                self.drop_range(0, write);
+            } else {
+                self.drop_range(read, write - read);
+            }
+        }
+    }
+}
+
+impl<S: Storage<Item = T>, T> StorageManager for BipMutRingBuf<S> {
+    type StoredType = T;
+    type S = S;
+
+    #[inline]
+    fn inner(&self) -> &S {
+        unsafe { &(*self.inner.get()) }
+    }
+
+    #[inline]
+    fn inner_mut(&self) -> &mut S {
+        unsafe { &mut (*self.inner.get()) }
+    }
+
+    #[inline]
+    fn inner_len(&self) -> usize {
+        self.inner_len.get()
+    }
+
+    #[inline]
+    fn history_len(&self) -> usize {
+        0
+    }
+}
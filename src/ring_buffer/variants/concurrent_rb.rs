@@ -1,21 +1,25 @@
 use core::cell::UnsafeCell;
+use core::fmt;
 use core::num::NonZeroUsize;
 use core::sync::atomic::AtomicU8;
 use core::sync::atomic::AtomicUsize;
 use core::sync::atomic::Ordering::{Acquire, Release};
 
 use crate::iterators::{ConsIter, ProdIter, WorkIter};
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::SharedStats;
 use crate::ring_buffer::storage::Storage;
 use crate::ring_buffer::variants::ring_buffer_trait::{
-    ConcurrentRB, IterManager, MutRB, PrivateIterManager, StorageManager,
+    ConcurrentRB, IterManager, MutRB, PrivateIterManager, StorageManager, debug_buffer,
 };
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
 use crossbeam_utils::CachePadded;
 
+#[cfg(feature = "vmem")]
+use crate::VmemStorage;
 use crate::ring_buffer::storage::impl_splits::impl_splits;
 #[cfg(feature = "alloc")]
 use crate::{HeapSplit, HeapStorage};
-#[cfg(not(feature = "vmem"))]
 use crate::{StackSplit, StackStorage};
 
 /// Concurrent mutable ring buffer. This buffer is useful for implementing types.
@@ -25,12 +29,16 @@ use crate::{StackSplit, StackStorage};
 pub struct ConcurrentMutRingBuf<S: Storage> {
     inner_len: NonZeroUsize,
     inner: UnsafeCell<S>,
+    history: usize,
 
     prod_idx: CachePadded<AtomicUsize>,
     work_idx: CachePadded<AtomicUsize>,
     cons_idx: CachePadded<AtomicUsize>,
 
     alive_iters: AtomicU8,
+
+    #[cfg(feature = "stats")]
+    stats: SharedStats,
 }
 
 impl<S: Storage<Item = T>, T> MutRB for ConcurrentMutRingBuf<S> {
@@ -48,15 +56,52 @@ impl<S: Storage<Item = T>, T> ConcurrentMutRingBuf<S> {
         ConcurrentMutRingBuf {
             inner_len: NonZeroUsize::new(value.len()).unwrap(),
             inner: value.into(),
+            history: 0,
 
             prod_idx: CachePadded::new(0.into()),
             work_idx: CachePadded::new(0.into()),
             cons_idx: CachePadded::new(0.into()),
             alive_iters: AtomicU8::default(),
+
+            #[cfg(feature = "stats")]
+            stats: SharedStats::default(),
         }
     }
 }
 
+impl<S: Storage<Item = T>, T: Copy> ConcurrentMutRingBuf<S> {
+    /// Keeps the last `k` consumed items readable through [`ConsIter::history`].
+    ///
+    /// The producer treats these items as still occupied, so the capacity of the buffer is reduced by `k`.
+    ///
+    /// Only buffers holding `Copy` items support this: consumed items are moved out of the buffer,
+    /// and only `Copy` ones stay valid to read once moved.
+    ///
+    /// # Panics
+    /// Panics if no room is left for new items, i.e. if `k` is greater than the length of the buffer minus `2`.
+    pub fn with_history(mut self, k: usize) -> Self {
+        assert!(
+            k + 2 <= self.inner_len.get(),
+            "history must leave room for at least one item"
+        );
+
+        self.history = k;
+        self
+    }
+}
+
+impl<S: Storage> fmt::Debug for ConcurrentMutRingBuf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_buffer(self, "ConcurrentMutRingBuf", f)
+    }
+}
+
+impl<S: Storage> Drop for ConcurrentMutRingBuf<S> {
+    fn drop(&mut self) {
+        self.drop_alive();
+    }
+}
+
 impl<S: Storage> PrivateIterManager for ConcurrentMutRingBuf<S> {
     fn set_alive_iters(&self, count: u8) {
         self.alive_iters.store(count, Release);
@@ -67,6 +112,12 @@ impl<S: Storage> PrivateIterManager for ConcurrentMutRingBuf<S> {
         self.alive_iters.fetch_sub(1, Release)
     }
 
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    fn stats(&self) -> &SharedStats {
+        &self.stats
+    }
+
     #[inline(always)]
     fn acquire_fence(&self) {
         #[cfg(not(feature = "thread_sanitiser"))]
@@ -133,4 +184,9 @@ impl<S: Storage<Item = T>, T> StorageManager for ConcurrentMutRingBuf<S> {
     fn inner_len(&self) -> usize {
         self.inner_len.get()
     }
+
+    #[inline]
+    fn history_len(&self) -> usize {
+        self.history
+    }
 }
@@ -1,15 +1,19 @@
 use core::cell::UnsafeCell;
+use core::fmt;
 use core::num::NonZeroUsize;
 
+#[cfg(feature = "vmem")]
+use crate::VmemStorage;
 use crate::iterators::{ConsIter, ProdIter, WorkIter};
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::SharedStats;
 use crate::ring_buffer::storage::Storage;
 use crate::ring_buffer::variants::ring_buffer_trait::{
-    IterManager, MutRB, PrivateIterManager, StorageManager,
+    IterManager, MutRB, PrivateIterManager, StorageManager, debug_buffer,
 };
 use crate::ring_buffer::wrappers::buf_ref::BufRef;
 #[cfg(feature = "alloc")]
 use crate::{HeapSplit, HeapStorage};
-#[cfg(not(feature = "vmem"))]
 use crate::{StackSplit, StackStorage};
 
 use crate::ring_buffer::storage::impl_splits::impl_splits;
@@ -21,12 +25,16 @@ use crate::ring_buffer::storage::impl_splits::impl_splits;
 pub struct LocalMutRingBuf<S: Storage> {
     inner_len: NonZeroUsize,
     inner: UnsafeCell<S>,
+    history: usize,
 
     prod_idx: UnsafeCell<usize>,
     work_idx: UnsafeCell<usize>,
     cons_idx: UnsafeCell<usize>,
 
     alive_iters: UnsafeCell<u8>,
+
+    #[cfg(feature = "stats")]
+    stats: SharedStats,
 }
 
 impl<S: Storage<Item = T>, T> MutRB for LocalMutRingBuf<S> {
@@ -42,16 +50,53 @@ impl<S: Storage<Item = T>, T> LocalMutRingBuf<S> {
         LocalMutRingBuf {
             inner_len: NonZeroUsize::new(value.len()).unwrap(),
             inner: value.into(),
+            history: 0,
 
             prod_idx: 0.into(),
             work_idx: 0.into(),
             cons_idx: 0.into(),
 
             alive_iters: 0.into(),
+
+            #[cfg(feature = "stats")]
+            stats: SharedStats::default(),
         }
     }
 }
 
+impl<S: Storage<Item = T>, T: Copy> LocalMutRingBuf<S> {
+    /// Keeps the last `k` consumed items readable through [`ConsIter::history`].
+    ///
+    /// The producer treats these items as still occupied, so the capacity of the buffer is reduced by `k`.
+    ///
+    /// Only buffers holding `Copy` items support this: consumed items are moved out of the buffer,
+    /// and only `Copy` ones stay valid to read once moved.
+    ///
+    /// # Panics
+    /// Panics if no room is left for new items, i.e. if `k` is greater than the length of the buffer minus `2`.
+    pub fn with_history(mut self, k: usize) -> Self {
+        assert!(
+            k + 2 <= self.inner_len.get(),
+            "history must leave room for at least one item"
+        );
+
+        self.history = k;
+        self
+    }
+}
+
+impl<S: Storage> fmt::Debug for LocalMutRingBuf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_buffer(self, "LocalMutRingBuf", f)
+    }
+}
+
+impl<S: Storage> Drop for LocalMutRingBuf<S> {
+    fn drop(&mut self) {
+        self.drop_alive();
+    }
+}
+
 impl<S: Storage> PrivateIterManager for LocalMutRingBuf<S> {
     fn set_alive_iters(&self, count: u8) {
         unsafe {
@@ -68,6 +113,12 @@ impl<S: Storage> PrivateIterManager for LocalMutRingBuf<S> {
     }
 
     fn acquire_fence(&self) {}
+
+    #[cfg(feature = "stats")]
+    #[inline(always)]
+    fn stats(&self) -> &SharedStats {
+        &self.stats
+    }
 }
 
 impl<S: Storage> IterManager for LocalMutRingBuf<S> {
@@ -130,4 +181,9 @@ impl<S: Storage<Item = T>, T> StorageManager for LocalMutRingBuf<S> {
     fn inner_len(&self) -> usize {
         self.inner_len.get()
     }
+
+    #[inline]
+    fn history_len(&self) -> usize {
+        self.history
+    }
 }
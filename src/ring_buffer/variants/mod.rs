@@ -1,4 +1,5 @@
 pub mod async_rb;
+pub mod bip_rb;
 pub mod concurrent_rb;
 pub mod local_rb;
 pub mod ring_buffer_trait;
@@ -1,4 +1,8 @@
-use crate::ring_buffer::storage::Storage;
+#[cfg(feature = "stats")]
+use crate::ring_buffer::stats::SharedStats;
+use crate::ring_buffer::storage::{MRBIndex, Storage};
+use core::fmt;
+use core::mem::needs_drop;
 
 /// Trait implemented by concurrent ring buffer.
 pub trait ConcurrentRB {}
@@ -11,10 +15,29 @@ pub trait MutRB:
     type Item;
 }
 
+/// Formats the state of a buffer. Used by the [`Debug`](fmt::Debug) implementations of all buffers.
+pub(crate) fn debug_buffer<B: IterManager + StorageManager>(
+    buf: &B,
+    name: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    f.debug_struct(name)
+        .field("len", &buf.inner_len())
+        .field("history_len", &buf.history_len())
+        .field("prod_index", &buf.prod_index())
+        .field("work_index", &buf.work_index())
+        .field("cons_index", &buf.cons_index())
+        .field("alive_iters", &buf.alive_iters())
+        .finish()
+}
+
 pub(crate) trait PrivateIterManager {
     fn set_alive_iters(&self, count: u8);
     fn drop_iter(&self) -> u8;
     fn acquire_fence(&self);
+
+    #[cfg(feature = "stats")]
+    fn stats(&self) -> &SharedStats;
 }
 
 /// Trait used to manage indices.
@@ -37,4 +60,38 @@ pub(crate) trait StorageManager {
     #[allow(clippy::mut_from_ref)]
     fn inner_mut(&self) -> &mut Self::S;
     fn inner_len(&self) -> usize;
+    /// Returns the number of consumed items which are kept readable.
+    fn history_len(&self) -> usize;
+
+    /// Drops `count` items, starting from `index` and wrapping if necessary.
+    ///
+    /// # Safety
+    /// Items within the range must be initialised and must not be used anymore.
+    #[inline]
+    unsafe fn drop_range(&self, index: usize, count: usize) {
+        if needs_drop::<Self::StoredType>() {
+            let len = self.inner_len();
+
+            for i in 0..count {
+                unsafe {
+                    self.inner()
+                        ._index((index + i) % len)
+                        .as_mut_ptr()
+                        .drop_in_place()
+                };
+            }
+        }
+    }
+
+    /// Drops alive items, i.e. those between the consumer and the producer.
+    #[inline]
+    fn drop_alive(&self)
+    where
+        Self: IterManager,
+    {
+        let (cons, prod) = (self.cons_index(), self.prod_index());
+        let len = self.inner_len();
+
+        unsafe { self.drop_range(cons, (prod + len - cons) % len) };
+    }
 }
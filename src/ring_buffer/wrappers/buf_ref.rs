@@ -34,7 +34,6 @@ impl<'buf, B: IterManager + PrivateIterManager> BufRef<'buf, B> {
         }
     }
 
-    #[cfg(not(feature = "vmem"))]
     pub(crate) fn from_ref(buf: &'buf mut B) -> Self {
         Self {
             inner: NonNull::from(buf),
@@ -1,2 +1,3 @@
 pub mod buf_ref;
+pub mod uninit_rb;
 pub mod unsafe_sync_cell;
@@ -0,0 +1,161 @@
+#[allow(unused_imports)]
+use crate::iterators::{ConsIter, ProdIter};
+use crate::ring_buffer::storage::MRBIndex;
+use crate::ring_buffer::variants::ring_buffer_trait::MutRB;
+#[cfg(doc)]
+use crate::{ConcurrentHeapRB, iterators::UninitProdIter};
+
+#[doc = r##"
+A ring buffer whose items have not been initialised yet.
+
+This struct is returned by `new_uninit` methods (e.g. [`ConcurrentHeapRB::new_uninit`]) and can be split
+like a normal buffer. The resulting producer is an [`UninitProdIter`], which only exposes operations
+that initialise the items they write, so that uninitialised memory can never be read through it.
+
+The normal buffer can be obtained via [`Self::into_init`], as well as the normal producer
+via [`UninitProdIter::into_init`]. Alternatively, [`Self::fill`] and [`Self::fill_with`] yield it
+after initialising every slot.
+"##]
+pub struct UninitRB<B: MutRB> {
+    pub(crate) inner: B,
+}
+
+impl<B: MutRB> UninitRB<B> {
+    pub(crate) fn new(inner: B) -> Self {
+        Self { inner }
+    }
+
+    /// Yields the underlying buffer.
+    ///
+    /// The buffer keeps track of which items have been initialised, so this transition is always
+    /// sound: the yielded buffer is empty, and items are initialised as they get pushed into it.
+    #[inline]
+    pub fn into_init(self) -> B {
+        self.inner
+    }
+
+    /// Initialises every slot the producer can write with a clone of `value`, yielding the underlying buffer.
+    ///
+    /// See [`Self::fill_with`].
+    pub fn fill(self, value: B::Item) -> B
+    where
+        B::Item: Clone,
+    {
+        self.fill_with(|| value.clone())
+    }
+
+    /// Initialises every slot the producer can write with the values returned by `f`, yielding the
+    /// underlying buffer.
+    ///
+    /// The yielded buffer is full: once split, its consumer can pop `len - 1` items right away,
+    /// while its producer has to wait for them to be consumed.
+    pub fn fill_with(self, mut f: impl FnMut() -> B::Item) -> B {
+        let buf = self.inner;
+
+        // A stack-allocated buffer may have been split before, so alive items are dropped,
+        // and indices reset before writing, in case `f` panics.
+        buf.drop_alive();
+        buf.set_prod_index(0);
+        buf.set_work_index(0);
+        buf.set_cons_index(0);
+
+        let count = buf.inner_len() - 1 - buf.history_len();
+
+        for i in 0..count {
+            unsafe { buf.inner()._index(i).as_mut_ptr().write(f()) };
+            buf.set_prod_index(i + 1);
+        }
+
+        buf
+    }
+}
+
+pub(crate) mod impl_uninit_splits {
+    #[cfg(feature = "alloc")]
+    macro_rules! impl_uninit_heap_splits {
+        ($t: tt) => {
+            impl<T> $t<T> {
+                #[doc = concat!("Creates a new [`", stringify!($t), "`] with given capacity, wrapped in an [`UninitRB`].")]
+                /// See [`Self::new_zeroed`] for the way the actual capacity is computed.
+                pub fn new_uninit(capacity: usize) -> UninitRB<Self> {
+                    UninitRB::new(Self::new_zeroed(capacity))
+                }
+            }
+
+            impl<T> UninitRB<$t<T>> {
+                /// Consumes the buffer, yielding two iterators. See:
+                /// - [`UninitProdIter`];
+                /// - [`ConsIter`].
+                pub fn split<'buf>(
+                    self,
+                ) -> (
+                    UninitProdIter<'buf, $t<T>>,
+                    ConsIter<'buf, $t<T>, false>,
+                ) {
+                    let (prod, cons) = HeapSplit::split(self.inner);
+                    (UninitProdIter::new(prod), cons)
+                }
+
+                /// Consumes the buffer, yielding three iterators. See:
+                /// - [`UninitProdIter`];
+                /// - [`WorkIter`];
+                /// - [`ConsIter`].
+                pub fn split_mut<'buf>(
+                    self,
+                ) -> (
+                    UninitProdIter<'buf, $t<T>>,
+                    WorkIter<'buf, $t<T>>,
+                    ConsIter<'buf, $t<T>, true>,
+                ) {
+                    let (prod, work, cons) = HeapSplit::split_mut(self.inner);
+                    (UninitProdIter::new(prod), work, cons)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_uninit_stack_splits {
+        ($t: tt) => {
+            impl<T, const N: usize> $t<T, N> {
+                #[doc = concat!("Creates a new [`", stringify!($t), "`], wrapped in an [`UninitRB`].")]
+                pub fn new_uninit() -> UninitRB<Self> {
+                    UninitRB::new(Self::new_zeroed())
+                }
+            }
+
+            impl<T, const N: usize> UninitRB<$t<T, N>> {
+                /// Borrows the buffer, yielding two iterators. See:
+                /// - [`UninitProdIter`];
+                /// - [`ConsIter`].
+                pub fn split(
+                    &mut self,
+                ) -> (
+                    UninitProdIter<'_, $t<T, N>>,
+                    ConsIter<'_, $t<T, N>, false>,
+                ) {
+                    let (prod, cons) = StackSplit::split(&mut self.inner);
+                    (UninitProdIter::new(prod), cons)
+                }
+
+                /// Borrows the buffer, yielding three iterators. See:
+                /// - [`UninitProdIter`];
+                /// - [`WorkIter`];
+                /// - [`ConsIter`].
+                pub fn split_mut(
+                    &mut self,
+                ) -> (
+                    UninitProdIter<'_, $t<T, N>>,
+                    WorkIter<'_, $t<T, N>>,
+                    ConsIter<'_, $t<T, N>, true>,
+                ) {
+                    let (prod, work, cons) = StackSplit::split_mut(&mut self.inner);
+                    (UninitProdIter::new(prod), work, cons)
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) use impl_uninit_heap_splits;
+    pub(crate) use impl_uninit_stack_splits;
+}
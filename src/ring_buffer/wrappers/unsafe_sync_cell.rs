@@ -4,17 +4,12 @@ use core::ptr::slice_from_raw_parts;
 
 /// Sync version of `UnsafeCell<MaybeUninit<T>>`.
 /// While it should not be used outside of this crate, it may be useful in certain scenarios.
+///
+/// As `MaybeUninit`, this cell never drops its content: buffers keep track of which items are alive
+/// and drop them when needed.
 #[repr(transparent)]
 pub struct UnsafeSyncCell<T>(UnsafeCell<MaybeUninit<T>>);
 
-impl<T> Drop for UnsafeSyncCell<T> {
-    fn drop(&mut self) {
-        if !UnsafeSyncCell::check_zeroed(self.0.get_mut().as_mut_ptr()) {
-            unsafe { self.0.get_mut().assume_init_drop() }
-        }
-    }
-}
-
 unsafe impl<T: Sync> Sync for UnsafeSyncCell<T> {}
 impl<T: Default> Default for UnsafeSyncCell<T> {
     /// Creates an `UnsafeSyncCell`, with the `Default` value for T.
@@ -19,7 +19,7 @@ async fn rb_fibonacci() {
     #[cfg(not(feature = "vmem"))]
     let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
     #[cfg(feature = "vmem")]
-    let buf = mutringbuf::AsyncHeapRB::from(vec![0; BUFFER_SIZE]);
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
 
     let (mut as_prod, mut as_work, mut as_cons) = buf.split_mut();
 
@@ -13,7 +13,7 @@ async fn test_full() {
     #[cfg(not(feature = "vmem"))]
     let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
     #[cfg(feature = "vmem")]
-    let buf = mutringbuf::AsyncHeapRB::from(vec![0; BUFFER_SIZE]);
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
 
     let (mut as_prod, mut as_cons) = buf.split();
 
@@ -42,7 +42,7 @@ async fn test_push_work_pop_single_and_slice() {
     #[cfg(not(feature = "vmem"))]
     let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
     #[cfg(feature = "vmem")]
-    let buf = mutringbuf::AsyncHeapRB::from(vec![0; BUFFER_SIZE]);
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
 
     let (mut as_prod, mut as_work, mut as_cons) = buf.split_mut();
 
@@ -64,11 +64,10 @@ async fn test_push_work_pop_single_and_slice() {
     let slice: Vec<i32> = (0..BUFFER_SIZE as i32 / 2).collect();
     as_prod.push_slice(&slice).await;
 
-    #[cfg(not(feature = "vmem"))]
-    if let Some((h, t)) = as_work.get_workable_slice_avail().await {
-        let len = h.len() + t.len();
+    if let Some(mut slices) = as_work.get_workable_slice_avail().await {
+        let len = slices.len();
 
-        for x in h.iter_mut().chain(t) {
+        for x in slices.iter_mut() {
             *x += 1;
         }
 
@@ -77,35 +76,126 @@ async fn test_push_work_pop_single_and_slice() {
         }
     }
 
+    if let Some(slices) = as_cons.peek_available().await {
+        for (x, y) in slices.iter().zip(&slice) {
+            assert_eq!(*x, y + 1);
+        }
+    }
+
+    drop(as_prod);
+    assert_eq!(as_cons.alive_iters(), 2);
+    drop(as_work);
+    assert_eq!(as_cons.alive_iters(), 1);
+}
+
+#[tokio::test]
+async fn test_positions() {
+    #[cfg(not(feature = "vmem"))]
+    let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
     #[cfg(feature = "vmem")]
-    if let Some(r) = as_work.get_workable_slice_avail().await {
-        let len = r.len();
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
 
-        for x in r {
-            *x += 1;
-        }
+    let (mut as_prod, mut as_cons) = buf.split();
 
-        unsafe {
-            as_work.advance(len);
-        }
+    for i in 0..BUFFER_SIZE as i32 * 2 {
+        as_prod.push(i).await;
+        assert_eq!(as_cons.pop().await, Some(i));
     }
 
+    assert_eq!(as_prod.position(), BUFFER_SIZE as u64 * 2);
+    assert_eq!(as_cons.total_pushed(), BUFFER_SIZE as u64 * 2);
+    assert_eq!(as_prod.total_popped(), BUFFER_SIZE as u64 * 2);
+
+    as_prod.push(0).await;
+
+    let mut as_cons = as_cons.detach();
+    unsafe { as_cons.advance(1) };
+    assert_eq!(as_cons.position(), BUFFER_SIZE as u64 * 2 + 1);
+    assert_eq!(as_cons.total_popped(), BUFFER_SIZE as u64 * 2);
+
+    unsafe { as_cons.go_back(1) };
+    assert_eq!(as_cons.position(), BUFFER_SIZE as u64 * 2);
+}
+
+#[tokio::test]
+async fn test_detached_seek() {
+    use mutringbuf::iterators::{SeekError, SeekFrom};
+
     #[cfg(not(feature = "vmem"))]
-    if let Some((h, t)) = as_cons.peek_available().await {
-        for (x, y) in h.iter().chain(t).zip(&slice) {
-            assert_eq!(*x, y + 1);
-        }
-    }
+    let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
+    #[cfg(feature = "vmem")]
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
 
+    let (mut as_prod, as_cons) = buf.split();
+
+    let slice: Vec<i32> = (0..BUFFER_SIZE as i32 - 1).collect();
+    as_prod.push_slice(&slice).await;
+
+    let mut as_cons = as_cons.detach();
+    assert_eq!(as_cons.offset_from_successor(), BUFFER_SIZE - 1);
+
+    assert_eq!(as_cons.seek(SeekFrom::Start(10)), Ok(10));
+    assert_eq!(
+        as_cons.seek(SeekFrom::Current(-20)),
+        Err(SeekError::BehindIndex)
+    );
+    assert_eq!(
+        as_cons.seek(SeekFrom::End(1)),
+        Err(SeekError::BeyondSuccessor)
+    );
+    assert_eq!(as_cons.rewind_to_successor(), BUFFER_SIZE as u64 - 1);
+    assert_eq!(
+        as_cons.seek(SeekFrom::Current(-1)),
+        Ok(BUFFER_SIZE as u64 - 2)
+    );
+
+    unsafe { as_cons.go_back(3) };
+    assert_eq!(as_cons.offset_from_successor(), 4);
+}
+
+#[tokio::test]
+async fn test_peek_at() {
+    #[cfg(not(feature = "vmem"))]
+    let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
     #[cfg(feature = "vmem")]
-    if let Some(r) = as_cons.peek_available().await {
-        for (x, y) in r.iter().zip(&slice) {
-            assert_eq!(*x, y + 1);
-        }
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
+
+    let (mut as_prod, mut as_work, mut as_cons) = buf.split_mut();
+
+    for i in 0..5 {
+        as_prod.push(i).await;
     }
 
-    drop(as_prod);
-    assert_eq!(as_cons.alive_iters(), 2);
-    drop(as_work);
-    assert_eq!(as_cons.alive_iters(), 1);
+    *as_work.get_workable_at(4).await.unwrap() += 10;
+    assert_eq!(as_work.get_workable_window().await.unwrap().len(), 5);
+    unsafe { as_work.advance(5) };
+
+    assert_eq!(as_cons.peek_at(4).await, Some(&14));
+    let window = as_cons.peek_window().await.unwrap();
+    assert_eq!(window.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 14]);
+}
+
+#[cfg(feature = "stats")]
+#[tokio::test]
+async fn test_stats_wakeups() {
+    #[cfg(not(feature = "vmem"))]
+    let buf = mutringbuf::AsyncStackRB::from([0; BUFFER_SIZE]);
+    #[cfg(feature = "vmem")]
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0; BUFFER_SIZE]);
+
+    let (mut as_prod, mut as_cons) = buf.split();
+
+    let popper = tokio::spawn(async move {
+        let value = as_cons.pop().await;
+        (value, as_cons.stats())
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    as_prod.push_slice(&[1]).await;
+
+    let (value, stats) = popper.await.unwrap();
+    assert_eq!(value, Some(1));
+    assert_eq!(stats.cons_wakeups, 1);
+    assert_eq!(stats.prod_wakeups, 0);
+    assert!(stats.failed_pops >= 1);
 }
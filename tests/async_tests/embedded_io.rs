@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use embedded_io_async::{BufRead, Read, Write};
+
+use crate::common_def;
+
+common_def!(buf);
+
+#[tokio::test]
+async fn test_embedded_io_async() {
+    #[cfg(not(feature = "vmem"))]
+    let buf = mutringbuf::AsyncStackRB::from([0u8; BUFFER_SIZE]);
+    #[cfg(feature = "vmem")]
+    let buf = mutringbuf::AsyncVmemRB::from(vec![0u8; BUFFER_SIZE]);
+
+    let (mut as_prod, mut as_cons) = buf.split();
+
+    let data: Vec<u8> = (0..BUFFER_SIZE * 2).map(|i| i as u8).collect();
+    let clone = data.clone();
+
+    let writer = tokio::spawn(async move {
+        // More than the buffer can hold: waits for the reader.
+        as_prod.write_all(&clone).await.unwrap();
+        as_prod.flush().await.unwrap();
+    });
+
+    let reader = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let mut read = Vec::new();
+        let mut dst = [0; 7];
+
+        let chunk = as_cons.fill_buf().await.unwrap();
+        assert!(!chunk.is_empty());
+        read.push(chunk[0]);
+        as_cons.consume(1);
+
+        while read.len() < data.len() {
+            let count = as_cons.read(&mut dst).await.unwrap();
+            read.extend_from_slice(&dst[..count]);
+        }
+
+        assert_eq!(read, data);
+    });
+
+    let (w, r) = tokio::join!(writer, reader);
+    w.unwrap();
+    r.unwrap();
+}
@@ -1,3 +1,5 @@
 #![cfg(feature = "async")]
 mod async_concurrent_fib;
 mod base;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_io;
@@ -1,22 +1,21 @@
 use crate::common_def;
-use mutringbuf::iterators::ProdIter;
+use mutringbuf::iterators::{ConsIter, ProdIter};
 use mutringbuf::{MRBIterator, MutRB};
-use std::slice::Iter;
+use std::rc::Rc;
 
 common_def!(buf);
 
-fn test_buf(v: Iter<usize>, mut prod: ProdIter<impl MutRB<Item = usize>>) {
-    #[cfg(not(feature = "vmem"))]
-    let b = {
-        let (h, t) = prod.get_workable_slice_avail().unwrap();
-        h.iter().chain(t.iter()).map(|e| *e).collect::<Vec<usize>>()
-    };
+fn test_buf<B: MutRB<Item = usize>>(mut prod: ProdIter<B>, mut cons: ConsIter<B, false>) {
+    // Buffers built from existing elements start empty.
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+    assert_eq!(cons.available(), 0);
 
-    #[cfg(feature = "vmem")]
-    let b = prod.get_workable_slice_avail().unwrap();
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
 
-    for (be, ve) in b.iter().zip(v) {
-        assert_eq!(*ve, *be);
+    for i in 0..BUFFER_SIZE - 1 {
+        assert_eq!(cons.pop(), Some(i));
     }
 }
 
@@ -27,23 +26,36 @@ fn test_new_buf() {
     #[cfg(feature = "alloc")]
     {
         use mutringbuf::{ConcurrentHeapRB, HeapSplit, LocalHeapRB};
-        let (prod, _) = ConcurrentHeapRB::from(v.clone().to_vec()).split();
-        test_buf(v.iter(), prod);
+        let (prod, cons) = ConcurrentHeapRB::from(v.clone().to_vec()).split();
+        test_buf(prod, cons);
 
-        let (prod, _) = LocalHeapRB::from(v.clone().to_vec()).split();
-        test_buf(v.iter(), prod);
+        let (prod, cons) = LocalHeapRB::from(v.clone().to_vec()).split();
+        test_buf(prod, cons);
     }
 
-    #[cfg(not(feature = "vmem"))]
     {
         use mutringbuf::{ConcurrentStackRB, LocalStackRB, StackSplit};
 
-        let mut buf = ConcurrentStackRB::from(v.clone());
-        let (prod, _) = buf.split();
-        test_buf(v.iter(), prod);
+        let mut buf = ConcurrentStackRB::from(v);
+        let (prod, cons) = buf.split();
+        test_buf(prod, cons);
 
-        let mut buf = LocalStackRB::from(v.clone());
-        let (prod, _) = buf.split();
-        test_buf(v.iter(), prod);
+        let mut buf = LocalStackRB::from(v);
+        let (prod, cons) = buf.split();
+        test_buf(prod, cons);
     }
 }
+
+#[test]
+fn test_from_drops_elements() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+
+    let value = Rc::new(0);
+
+    let (prod, cons) = ConcurrentHeapRB::from(vec![value.clone(); BUFFER_SIZE]).split();
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
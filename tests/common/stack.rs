@@ -1,7 +1,5 @@
-#![cfg(not(feature = "vmem"))]
-
 use crate::common_def;
-use mutringbuf::{ConcurrentStackRB, LocalStackRB, MRBIterator as MRBIt};
+use mutringbuf::{ConcurrentStackRB, FollowerIter, LocalStackRB, MRBIterator as MRBIt, StackSplit};
 use std::{
     sync::Arc,
     sync::atomic::Ordering::{Acquire, Release},
@@ -10,7 +8,7 @@ use std::{
     time::Duration,
 };
 
-common_def!();
+common_def!(buf);
 
 #[test]
 fn test_local_stack() {
@@ -9,7 +9,7 @@ fn test_uninit() {
     common_def!(buf);
 
     // Indices from 0 to RB_SIZE+1 are uninitialised
-    let buf = unsafe { ConcurrentHeapRB::new_zeroed(BUFFER_SIZE) };
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
     let (mut prod, mut cons) = buf.split();
 
     let slice = (0..BUFFER_SIZE - 1)
@@ -17,32 +17,34 @@ fn test_uninit() {
         .collect::<Vec<Rc<usize>>>();
 
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // RB_SIZE indices out of RB_SIZE+1 are initialised.
+        prod.push(x.clone()).unwrap();
+    }
+    assert!(slice.iter().all(|x| Rc::strong_count(x) == 2));
 
     unsafe {
         cons.advance(BUFFER_SIZE - 1);
-    }
+    } // Skipped items are dropped.
+    assert!(slice.iter().all(|x| Rc::strong_count(x) == 1));
 
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // All indices are now initialised. It would be now safe to use normal methods from prod instead of `*_init` ones.
+        prod.push(x.clone()).unwrap();
+    }
 
     for _ in &slice {
-        unsafe {
-            cons.pop_move().unwrap();
-        }
-    } // 1 index out of RB_SIZE+1 is initialised. `*_init` methods must be used.
+        cons.pop().unwrap();
+    } // Popped items are moved out and dropped here.
+    assert!(slice.iter().all(|x| Rc::strong_count(x) == 1));
 
     for x in &slice {
-        prod.push_init(x.clone()).unwrap();
-    } // RB_SIZE indices out of RB_SIZE+1 are initialised.
+        prod.push(x.clone()).unwrap();
+    }
 
     unsafe {
         cons.advance(BUFFER_SIZE - 1);
     }
 
-    prod.push_slice_clone_init(&slice).unwrap(); // All indices are now initialised. It would be now safe to use normal methods from prod instead of `*_init` ones.
+    prod.push_slice_clone(&slice).unwrap();
+    assert!(slice.iter().all(|x| Rc::strong_count(x) == 2));
 
     unsafe {
         cons.advance(BUFFER_SIZE - 1);
@@ -51,11 +53,12 @@ fn test_uninit() {
     prod.push_slice_clone(&slice).unwrap();
 
     for _ in &slice {
-        unsafe {
-            cons.pop_move().unwrap();
-        }
+        cons.pop().unwrap();
     }
 
+    prod.push_slice_clone(&slice).unwrap();
+
     drop(prod);
-    drop(cons);
+    drop(cons); // Items still in the buffer are dropped along with it.
+    assert!(slice.iter().all(|x| Rc::strong_count(x) == 1));
 }
@@ -0,0 +1,144 @@
+use mutringbuf::BipHeapRB;
+
+fn new_buf<T>() -> BipHeapRB<T> {
+    BipHeapRB::new_zeroed(16)
+}
+
+#[test]
+fn test_contiguous_regions() {
+    let mut buf = new_buf();
+    let (mut prod, mut cons) = buf.split();
+    let len = prod.buf_len();
+
+    assert!(cons.peek().is_none());
+    assert_eq!(prod.available(), len);
+
+    prod.push_slice(&(0..len - 4).collect::<Vec<usize>>())
+        .unwrap();
+    assert_eq!(cons.peek().unwrap().len(), len - 4);
+    cons.release(len - 6);
+
+    // Not enough room at the end: the producer restarts from the beginning.
+    assert_eq!(prod.available(), len - 7);
+    assert!(prod.reserve(len - 6).is_none());
+    prod.reserve(5).unwrap().fill_with(|| 100);
+
+    // The consumer reads up to the watermark, then restarts.
+    assert_eq!(cons.peek().unwrap(), &[len - 6, len - 5]);
+    assert_eq!(cons.pop(), Some(len - 6));
+    assert_eq!(cons.pop(), Some(len - 5));
+    assert_eq!(cons.peek().unwrap(), &[100; 5]);
+    cons.release(5);
+
+    assert!(cons.peek().is_none());
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_grant_commit() {
+    let mut buf = new_buf();
+    let (mut prod, mut cons) = buf.split();
+
+    let mut grant = prod.reserve(4).unwrap();
+    assert_eq!(grant.len(), 4);
+    for (i, slot) in grant.as_uninit_mut().iter_mut().enumerate() {
+        slot.write(i);
+    }
+    // Only the first two items are committed.
+    unsafe { grant.commit(2) };
+    assert_eq!(cons.peek().unwrap(), &[0, 1]);
+
+    // Dropping a grant does not commit anything.
+    assert!(prod.reserve(3).is_some());
+    assert_eq!(cons.available(), 2);
+
+    assert_eq!(prod.push(2), Ok(()));
+    assert_eq!(cons.peek().unwrap(), &[0, 1, 2]);
+}
+
+#[test]
+fn test_full() {
+    let mut buf = new_buf();
+    let (mut prod, mut cons) = buf.split();
+    let len = prod.buf_len();
+
+    for i in 0..len {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(prod.push(0), Err(0));
+    assert_eq!(prod.available(), 0);
+
+    // The region before the consumer can be reserved, leaving one location to tell a full buffer from an empty one.
+    cons.release(3);
+    assert_eq!(prod.available(), 2);
+    assert!(prod.reserve(3).is_none());
+    prod.push_slice(&[0, 1]).unwrap();
+
+    cons.release(len - 3);
+    assert_eq!(cons.peek().unwrap(), &[0, 1]);
+}
+
+#[test]
+fn test_items_drop() {
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+    let mut buf = new_buf();
+    let (mut prod, mut cons) = buf.split();
+    let len = prod.buf_len();
+
+    for _ in 0..len {
+        prod.push(value.clone()).unwrap();
+    }
+    cons.release(len - 2);
+    assert_eq!(Rc::strong_count(&value), 3);
+
+    // Restart from the beginning, so that alive items are split.
+    prod.reserve(4).unwrap().fill_with(|| value.clone());
+    assert_eq!(Rc::strong_count(&value), 7);
+
+    drop(buf);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_threads() {
+    const ITEMS: usize = 10_000;
+
+    let mut buf = new_buf();
+    let (mut prod, mut cons) = buf.split();
+
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            let mut i = 0;
+            while i < ITEMS {
+                let count = (i % 5 + 1).min(ITEMS - i);
+
+                match prod.reserve(count) {
+                    Some(grant) => grant.fill_with(|| {
+                        i += 1;
+                        i - 1
+                    }),
+                    None => std::thread::yield_now(),
+                }
+            }
+        });
+
+        s.spawn(move || {
+            let mut expected = 0;
+            while expected < ITEMS {
+                match cons.peek() {
+                    Some(items) => {
+                        let len = items.len();
+                        for x in items {
+                            assert_eq!(*x, expected);
+                            expected += 1;
+                        }
+                        cons.release(len);
+                    }
+                    None => std::thread::yield_now(),
+                }
+            }
+        });
+    });
+}
@@ -5,7 +5,7 @@ use std::thread;
 use std::time::Duration;
 
 use crate::{common_def, get_buf};
-use mutringbuf::MRBIterator;
+use mutringbuf::{FollowerIter, MRBIterator};
 
 common_def!();
 
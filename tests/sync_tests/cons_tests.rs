@@ -65,7 +65,7 @@ fn test_pop_slice_exact() {
 
     fill_buf(&mut prod, BUFFER_SIZE - 1);
 
-    let (head, tail) = cons.peek_slice(BUFFER_SIZE - 1).unwrap();
+    let (head, tail) = cons.peek_slice(BUFFER_SIZE - 1).unwrap().as_slices();
     assert!(!head.is_empty() || !tail.is_empty());
 
     for (p, i) in [head, tail].concat().iter().zip(0..BUFFER_SIZE - 1) {
@@ -83,7 +83,7 @@ fn test_pop_avail_nw_exact() {
 
     fill_buf(&mut prod, BUFFER_SIZE - 1);
 
-    let (head, tail) = cons.peek_available().unwrap();
+    let (head, tail) = cons.peek_available().unwrap().as_slices();
     assert_eq!(head.len() + tail.len(), BUFFER_SIZE - 1);
 
     for (p, i) in [head, tail].concat().iter().zip(0..BUFFER_SIZE - 1) {
@@ -101,14 +101,14 @@ fn test_pop_slice_seam() {
 
     fill_buf(&mut prod, BUFFER_SIZE / 2);
 
-    let (head, tail) = cons.peek_available().unwrap();
+    let (head, tail) = cons.peek_available().unwrap().as_slices();
     assert_eq!(head.len(), BUFFER_SIZE / 2);
     assert!(tail.is_empty());
     unsafe { cons.advance(BUFFER_SIZE / 2) };
 
     fill_buf(&mut prod, BUFFER_SIZE);
 
-    let (head, tail) = cons.peek_available().unwrap();
+    let (head, tail) = cons.peek_available().unwrap().as_slices();
     assert_eq!(head.len(), BUFFER_SIZE.div_ceil(2));
     assert_eq!(tail.len(), BUFFER_SIZE / 2 - 1);
 
@@ -0,0 +1,89 @@
+use crate::{common_def, get_buf};
+use mutringbuf::{FollowerIter, MRBIterator};
+
+common_def!();
+
+#[test]
+fn test_peek_contiguous() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+    let mut scratch = [0; 8];
+
+    for i in 0..4 {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(
+        cons.peek_contiguous(4, &mut scratch),
+        Some(&[0, 1, 2, 3][..])
+    );
+    assert_eq!(cons.peek_contiguous(5, &mut scratch), None);
+    // No wrap: the scratch buffer is not used.
+    assert_eq!(scratch, [0; 8]);
+
+    cons.reset_index();
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 7 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    assert_eq!(
+        cons.peek_contiguous(6, &mut scratch),
+        Some(&[0, 1, 2, 3, 4, 5][..])
+    );
+    assert_eq!(cons.available(), 6);
+}
+
+#[test]
+fn test_with_contiguous_mut() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+    let mut scratch = vec![0; 8];
+
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    unsafe { work.advance(BUFFER_SIZE - 3) };
+    cons.reset_index();
+
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    let sum = work.with_contiguous_mut(6, &mut scratch, |s| {
+        assert_eq!(s.len(), 6);
+        s.iter_mut().for_each(|x| *x *= 10);
+        s.iter().sum::<usize>()
+    });
+    assert_eq!(sum, Some(150));
+    assert!(work.with_contiguous_mut(7, &mut scratch, |_| ()).is_none());
+
+    unsafe { work.advance(6) };
+
+    // Changes have been written back into the buffer.
+    for i in 0..6 {
+        assert_eq!(cons.pop(), Some(i * 10));
+    }
+}
+
+#[cfg(not(feature = "vmem"))]
+#[test]
+#[should_panic]
+fn test_scratch_too_short() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    for _ in 0..BUFFER_SIZE - 2 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    for i in 0..4 {
+        prod.push(i).unwrap();
+    }
+
+    cons.peek_contiguous(4, &mut [0; 2]);
+}
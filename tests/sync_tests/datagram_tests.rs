@@ -0,0 +1,119 @@
+use mutringbuf::iterators::{Datagram, FdError};
+use mutringbuf::{DatagramRing, HeapSplit, MRBIterator};
+use std::net::UdpSocket;
+use std::os::fd::AsRawFd;
+
+const SLOT_SIZE: usize = 32;
+
+fn loopback() -> (UdpSocket, UdpSocket) {
+    let rx = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let tx = UdpSocket::bind("127.0.0.1:0").unwrap();
+    rx.set_nonblocking(true).unwrap();
+    (rx, tx)
+}
+
+#[test]
+fn test_datagram_slot() {
+    let mut d = Datagram::<4>::default();
+    assert!(d.payload().is_empty());
+    assert_eq!(d.addr(), None);
+
+    d.set_payload(&[1, 2, 3]);
+    assert_eq!(d.payload(), &[1, 2, 3]);
+
+    let addr = "[::1]:1234".parse().unwrap();
+    d.set_addr(Some(addr));
+    assert_eq!(d.addr(), Some(addr));
+    d.set_addr(None);
+    assert_eq!(d.addr(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_datagram_slot_too_long() {
+    Datagram::<4>::default().set_payload(&[0; 5]);
+}
+
+#[test]
+fn test_recv_datagrams() {
+    let (rx, tx) = loopback();
+
+    let mut buf = DatagramRing::<SLOT_SIZE>::new_zeroed(8);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(
+        prod.recv_datagrams(rx.as_raw_fd(), 8),
+        Err(FdError::WouldBlock)
+    );
+
+    // Move indices near the end of the buffer, so that slots wrap around.
+    for _ in 0..5 {
+        prod.push(Datagram::default()).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..6u8 {
+        tx.send_to(&[i; 4], rx.local_addr().unwrap()).unwrap();
+    }
+    // Larger than a slot.
+    tx.send_to(&[9; SLOT_SIZE + 1], rx.local_addr().unwrap())
+        .unwrap();
+
+    let mut received = 0;
+    while received < 7 {
+        match prod.recv_datagrams(rx.as_raw_fd(), 7 - received) {
+            Ok(n) => received += n,
+            Err(FdError::WouldBlock) => continue,
+            Err(e) => panic!("{e}"),
+        }
+    }
+    // The buffer is full.
+    assert_eq!(prod.recv_datagrams(rx.as_raw_fd(), 1), Ok(0));
+
+    for i in 0..6u8 {
+        let d = cons.pop().unwrap();
+        assert_eq!(d.payload(), &[i; 4]);
+        assert_eq!(d.addr(), Some(tx.local_addr().unwrap()));
+        assert!(!d.is_truncated());
+    }
+
+    let truncated = cons.pop().unwrap();
+    assert!(truncated.is_truncated());
+    assert_eq!(truncated.payload(), &[9; SLOT_SIZE]);
+}
+
+#[test]
+fn test_send_datagrams() {
+    let (rx, tx) = loopback();
+
+    let mut buf = DatagramRing::<SLOT_SIZE>::new_zeroed(8);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(cons.send_datagrams(tx.as_raw_fd(), 8), Ok(0));
+
+    for _ in 0..5 {
+        prod.push(Datagram::default()).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..6u8 {
+        let mut d = Datagram::default();
+        d.set_payload(&[i; 4]);
+        d.set_addr(Some(rx.local_addr().unwrap()));
+        prod.push(d).unwrap();
+    }
+
+    let mut sent = 0;
+    while sent < 6 {
+        sent += cons.send_datagrams(tx.as_raw_fd(), 6 - sent).unwrap();
+    }
+    assert_eq!(cons.available(), 0);
+
+    rx.set_nonblocking(false).unwrap();
+    let mut recv = [0; SLOT_SIZE];
+    for i in 0..6u8 {
+        let (len, from) = rx.recv_from(&mut recv).unwrap();
+        assert_eq!(&recv[..len], &[i; 4]);
+        assert_eq!(from, tx.local_addr().unwrap());
+    }
+}
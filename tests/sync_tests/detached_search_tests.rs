@@ -0,0 +1,94 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[test]
+fn test_find() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    let mut cons = cons.detach();
+
+    assert_eq!(cons.find(|_| true), None);
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 5 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    cons.sync_index();
+
+    for i in 1..=10 {
+        prod.push(i).unwrap();
+    }
+
+    assert_eq!(cons.find(|x| *x == 3), Some(2));
+    assert_eq!(cons.find(|x| *x > 6), Some(6));
+    assert_eq!(cons.position_of(&10), Some(9));
+    assert_eq!(cons.position_of(&11), None);
+
+    // Searching does not move the iterator.
+    assert_eq!(cons.available(), 10);
+}
+
+#[test]
+fn test_scan_until() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    let mut cons = cons.detach();
+
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    cons.sync_index();
+
+    for i in 1..=10 {
+        prod.push(i).unwrap();
+    }
+
+    assert_eq!(cons.scan_until(|x| *x == 5), Some(4));
+    assert_eq!(cons.get_workable().copied(), Some(5));
+    assert_eq!(cons.available(), 6);
+
+    // The global index does not move until synced.
+    assert_eq!(cons.cons_index(), BUFFER_SIZE - 3);
+
+    assert_eq!(cons.scan_until(|x| *x == 42), None);
+    assert_eq!(cons.available(), 0);
+
+    let mut cons = cons.attach();
+    assert_eq!(cons.index(), 7);
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_find_subslice() {
+    #[cfg(not(feature = "vmem"))]
+    let mut buf = mutringbuf::ConcurrentStackRB::from([0u8; BUFFER_SIZE]);
+    #[cfg(feature = "vmem")]
+    let mut buf = mutringbuf::ConcurrentHeapRB::from(vec![0u8; BUFFER_SIZE]);
+    let (mut prod, cons) = buf.split();
+
+    let mut cons = cons.detach();
+
+    for _ in 0..BUFFER_SIZE - 4 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    cons.sync_index();
+
+    prod.push_slice(b"xxAB\xCD\xEFyyAB").unwrap();
+
+    // The sync word straddles the end of the buffer.
+    assert_eq!(cons.find_subslice(b"AB\xCD\xEF"), Some(2));
+    assert_eq!(cons.find_subslice(b"yyAB"), Some(6));
+    assert_eq!(cons.find_subslice(b"ABC"), None);
+    assert_eq!(cons.find_subslice(b"yyABx"), None);
+    assert_eq!(cons.find_subslice(b""), Some(0));
+
+    unsafe { cons.advance(3) };
+    assert_eq!(cons.find_subslice(b"AB"), Some(5));
+}
@@ -0,0 +1,171 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[test]
+fn test_cons_commit_rollback() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+
+    let mut cons = cons.detach();
+
+    assert_eq!(cons.peek_ref(), Some(&0));
+    unsafe { cons.advance(3) };
+    assert_eq!(cons.peek_ref(), Some(&3));
+    assert_eq!(cons.available(), 7);
+
+    // Nothing has been consumed yet.
+    cons.rollback();
+    assert_eq!(cons.peek_ref(), Some(&0));
+    assert_eq!(cons.position(), 0);
+    assert_eq!(prod.available(), BUFFER_SIZE - 11);
+
+    unsafe { cons.advance(4) };
+    cons.commit();
+    assert_eq!(prod.available(), BUFFER_SIZE - 7);
+
+    // Rolling back after a commit does not go beyond it.
+    unsafe { cons.advance(2) };
+    cons.rollback();
+    assert_eq!(cons.peek_ref(), Some(&4));
+    assert_eq!(cons.total_popped(), 4);
+
+    let mut cons = cons.attach();
+    assert_eq!(cons.pop(), Some(4));
+}
+
+#[test]
+fn test_prod_commit_abort() {
+    let mut buf = get_buf!(Concurrent);
+    let (prod, mut cons) = buf.split();
+
+    let mut prod = prod.detach();
+
+    for i in 0..5 {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(prod.pending(), 5);
+    // Written items are not visible to the consumer until committed.
+    assert_eq!(cons.available(), 0);
+
+    prod.commit();
+    assert_eq!(prod.pending(), 0);
+    assert_eq!(cons.available(), 5);
+
+    prod.push_slice(&[5, 6, 7]).unwrap();
+    prod.push_slice_clone(&[8]).unwrap();
+    assert_eq!(prod.pending(), 4);
+    assert_eq!(prod.position(), 9);
+
+    prod.abort();
+    assert_eq!(prod.pending(), 0);
+    assert_eq!(prod.position(), 5);
+    assert_eq!(cons.available(), 5);
+
+    prod.push(10).unwrap();
+    let _prod = prod.attach();
+
+    for i in 0..5 {
+        assert_eq!(cons.pop(), Some(i));
+    }
+    assert_eq!(cons.pop(), Some(10));
+    assert_eq!(cons.pop(), None);
+}
+
+#[test]
+fn test_prod_full() {
+    let mut buf = get_buf!(Concurrent);
+    let (prod, _cons) = buf.split();
+
+    let mut prod = prod.detach();
+
+    for i in 0..BUFFER_SIZE - 1 {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(prod.push(0), Err(0));
+    assert!(prod.push_slice(&[0]).is_none());
+
+    prod.abort();
+    assert!(prod.push_slice(&[0; BUFFER_SIZE - 1]).is_some());
+}
+
+#[test]
+fn test_prod_abort_drops_items() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (prod, cons) = buf.split();
+
+    let mut prod = prod.detach();
+
+    for _ in 0..5 {
+        prod.push(value.clone()).unwrap();
+    }
+    prod.commit();
+
+    for _ in 0..3 {
+        prod.push(value.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), 9);
+
+    prod.abort();
+    assert_eq!(Rc::strong_count(&value), 6);
+
+    // Dropping a detached producer aborts pending items.
+    for _ in 0..3 {
+        prod.push(value.clone()).unwrap();
+    }
+    drop(prod);
+    assert_eq!(Rc::strong_count(&value), 6);
+
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_cons_commit_partial() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+
+    let mut cons = cons.detach();
+
+    unsafe { cons.advance(6) };
+    assert_eq!(cons.pending(), 6);
+
+    cons.commit_partial(4);
+    assert_eq!(cons.pending(), 2);
+    assert_eq!(cons.total_popped(), 4);
+    assert_eq!(prod.available(), BUFFER_SIZE - 7);
+
+    // Items which are still pending can be rolled back.
+    cons.rollback();
+    assert_eq!(cons.pending(), 0);
+    assert_eq!(cons.peek_ref(), Some(&4));
+
+    let mut cons = cons.attach();
+    assert_eq!(cons.pop(), Some(4));
+}
+
+#[test]
+#[should_panic]
+fn test_cons_commit_partial_beyond_pending() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    prod.push(0).unwrap();
+
+    let mut cons = cons.detach();
+    cons.commit_partial(1);
+}
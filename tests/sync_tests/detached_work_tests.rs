@@ -148,3 +148,83 @@ fn test_work_go_back() {
 
     assert_eq!(work.index(), 0);
 }
+
+#[test]
+fn test_work_go_back_wrapping() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    fill_buf(&mut prod);
+    unsafe {
+        work.advance(BUFFER_SIZE - 1);
+        cons.advance(BUFFER_SIZE - 1);
+    }
+    fill_buf(&mut prod);
+
+    let mut work = work.detach();
+    unsafe { work.advance(BUFFER_SIZE / 2) };
+
+    // Local index is now beyond the end of the buffer, so going back has to wrap.
+    let idx = work.index();
+    assert!(idx < BUFFER_SIZE / 2);
+
+    unsafe { work.go_back(idx + 2) };
+    assert_eq!(work.index(), BUFFER_SIZE - 2);
+}
+
+#[test]
+fn test_work_seek() {
+    use mutringbuf::iterators::{SeekError, SeekFrom};
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, work, mut cons) = buf.split_mut();
+
+    let mut work = work.detach();
+
+    fill_buf(&mut prod);
+    assert_eq!(work.seek(SeekFrom::End(0)), Ok(BUFFER_SIZE as u64 - 1));
+    work.sync_index();
+    unsafe { cons.advance(BUFFER_SIZE - 1) };
+
+    // Global index of the worker is at `BUFFER_SIZE - 1`, so the window wraps around the end of the buffer.
+    let start = BUFFER_SIZE as u64 - 1;
+    fill_buf(&mut prod);
+    let end = start + BUFFER_SIZE as u64 - 1;
+
+    assert_eq!(work.offset_from_successor(), BUFFER_SIZE - 1);
+
+    // Forward, wrapping.
+    assert_eq!(work.seek(SeekFrom::Current(5)), Ok(start + 5));
+    assert_eq!(work.index(), 4);
+    assert_eq!(work.available(), BUFFER_SIZE - 6);
+    assert_eq!(work.get_workable().copied(), Some(5));
+
+    // Backward, wrapping.
+    assert_eq!(work.seek(SeekFrom::Current(-4)), Ok(start + 1));
+    assert_eq!(work.index(), 0);
+    assert_eq!(work.seek(SeekFrom::Current(-1)), Ok(start));
+    assert_eq!(work.index(), BUFFER_SIZE - 1);
+    assert_eq!(work.get_workable().copied(), Some(0));
+
+    // Out of bounds.
+    assert_eq!(
+        work.seek(SeekFrom::Current(-1)),
+        Err(SeekError::BehindIndex)
+    );
+    assert_eq!(work.seek(SeekFrom::End(1)), Err(SeekError::BeyondSuccessor));
+    assert_eq!(
+        work.seek(SeekFrom::Start(start - 1)),
+        Err(SeekError::BehindIndex)
+    );
+    assert_eq!(work.position(), start);
+
+    assert_eq!(work.seek(SeekFrom::End(-3)), Ok(end - 3));
+    assert_eq!(work.offset_from_successor(), 3);
+    assert_eq!(work.seek(SeekFrom::Start(start + 10)), Ok(start + 10));
+    assert_eq!(work.rewind_to_successor(), end);
+    assert_eq!(work.offset_from_successor(), 0);
+
+    // The global index has not moved.
+    assert_eq!(work.total_worked(), start);
+    assert_eq!(work.work_index(), BUFFER_SIZE - 1);
+}
@@ -0,0 +1,105 @@
+use crate::{common_def, get_buf};
+use mutringbuf::{BufferState, MRBIterator};
+
+common_def!();
+
+#[test]
+fn test_debug() {
+    let mut buf = get_buf!(Concurrent);
+    assert!(format!("{buf:?}").starts_with("ConcurrentMutRingBuf { len: "));
+
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(work.available(), 3);
+
+    assert_eq!(
+        format!("{prod:?}"),
+        format!(
+            "ProdIter {{ role: Prod, index: 3, position: 3, cached_avail: {}, buf_len: {BUFFER_SIZE}, alive_iters: 3 }}",
+            BUFFER_SIZE - 4
+        )
+    );
+    assert_eq!(
+        format!("{work:?}"),
+        format!(
+            "WorkIter {{ role: Work, index: 0, position: 0, cached_avail: 3, buf_len: {BUFFER_SIZE}, alive_iters: 3 }}"
+        )
+    );
+
+    unsafe { work.advance(2) };
+    let mut cons = cons.detach();
+    unsafe { cons.advance(1) };
+
+    assert_eq!(
+        format!("{cons:?}"),
+        format!(
+            "Detached {{ inner: ConsIter {{ role: Cons, index: 1, position: 1, cached_avail: 0, buf_len: {BUFFER_SIZE}, alive_iters: 3 }}, atomic_index: 0 }}"
+        )
+    );
+}
+
+#[test]
+fn test_buffer_state() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    // Move indices near the end of the buffer, so that items wrap around.
+    for _ in 0..BUFFER_SIZE - 2 {
+        prod.push(0).unwrap();
+    }
+    unsafe { work.advance(BUFFER_SIZE - 2) };
+    cons.reset_index();
+
+    prod.push_slice(&[0; 6]).unwrap();
+    unsafe { work.advance(4) };
+    cons.pop().unwrap();
+
+    let state = cons.buffer_state();
+    assert_eq!(state, prod.buffer_state());
+    assert_eq!(
+        state,
+        BufferState {
+            buf_len: BUFFER_SIZE,
+            history_len: 0,
+            prod_index: 4,
+            work_index: 2,
+            cons_index: BUFFER_SIZE - 1,
+        }
+    );
+    assert_eq!(state.occupied(), 5);
+    assert_eq!(state.free(), BUFFER_SIZE - 6);
+    assert_eq!(state.free(), prod.available());
+    assert_eq!(state.stage_lag(), (2, 3));
+}
+
+#[test]
+fn test_occupancy_map() {
+    use mutringbuf::StackSplit;
+
+    let mut buf = mutringbuf::LocalStackRB::from([0; 10]).with_history(1);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(cons.buffer_state().occupancy_map(5).to_string(), "[.....]");
+
+    prod.push_slice(&[0; 8]).unwrap();
+    for _ in 0..3 {
+        cons.pop().unwrap();
+    }
+
+    let state = cons.buffer_state();
+    assert_eq!((state.occupied(), state.free()), (5, 3));
+    assert_eq!(state.occupancy_map(10).to_string(), "[...#####..]");
+    assert_eq!(state.occupancy_map(5).to_string(), "[.+##.]");
+    assert_eq!(state.occupancy_map(100).to_string(), "[...#####..]");
+
+    // Occupied slots wrap around the end of the buffer.
+    prod.push_slice(&[0; 3]).unwrap();
+    for _ in 0..4 {
+        cons.pop().unwrap();
+    }
+    assert_eq!(
+        cons.buffer_state().occupancy_map(10).to_string(),
+        "[#......###]"
+    );
+}
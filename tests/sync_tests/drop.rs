@@ -48,3 +48,114 @@ pub fn drop_everything() {
     drop(work);
     drop(cons);
 }
+
+#[test]
+pub fn items_drop_test() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentHeapRB::from(vec![value.clone(); BUFFER_SIZE]);
+    // Initial items are dropped, as the buffer starts empty.
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let (mut prod, mut cons) = buf.split();
+
+    for _ in 0..10 {
+        prod.push(value.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), 11);
+
+    // Popped items are moved out.
+    let popped = cons.pop().unwrap();
+    assert_eq!(Rc::strong_count(&value), 11);
+    drop(popped);
+    assert_eq!(Rc::strong_count(&value), 10);
+
+    // Skipped items are dropped.
+    cons.peek_ref().unwrap();
+    unsafe { cons.advance(1) };
+    assert_eq!(Rc::strong_count(&value), 9);
+
+    cons.reset_index();
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    for _ in 0..BUFFER_SIZE - 1 {
+        prod.push(value.clone()).unwrap();
+    }
+
+    // Items still in the buffer are dropped along with it.
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+pub fn detached_items_drop_test() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, cons) = buf.split();
+
+    for _ in 0..10 {
+        prod.push(value.clone()).unwrap();
+    }
+
+    let mut cons = cons.detach();
+    unsafe { cons.advance(5) };
+    // Items are dropped only once the index is synchronised.
+    assert_eq!(Rc::strong_count(&value), 11);
+
+    unsafe { cons.go_back(2) };
+    cons.sync_index();
+    assert_eq!(Rc::strong_count(&value), 8);
+
+    let cons = cons.attach();
+    assert_eq!(Rc::strong_count(&value), 8);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+pub fn resplit_without_worker_test() {
+    use mutringbuf::{LocalStackRB, StackSplit};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+    let mut buf = LocalStackRB::<Rc<u32>, 8>::new_zeroed();
+
+    {
+        let (mut prod, mut cons) = buf.split();
+
+        for _ in 0..5 {
+            prod.push(value.clone()).unwrap();
+        }
+        for _ in 0..5 {
+            cons.pop().unwrap();
+        }
+        for _ in 0..2 {
+            prod.push(value.clone()).unwrap();
+        }
+        cons.pop().unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    // The worker must start within the stored items, even if the previous split had none.
+    let (_prod, mut work, mut cons) = buf.split_mut();
+    assert_eq!(work.available(), 1);
+    unsafe { work.advance(1) };
+
+    let mut popped = 0;
+    while let Some(v) = cons.pop() {
+        assert_eq!(*v, 0);
+        popped += 1;
+    }
+    assert_eq!(popped, 1);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,146 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+common_def!();
+
+trait Command {
+    fn run(&mut self) -> u64;
+}
+
+struct Small(u8);
+
+impl Command for Small {
+    fn run(&mut self) -> u64 {
+        self.0 as u64
+    }
+}
+
+#[repr(align(16))]
+struct Aligned([u64; 3]);
+
+impl Command for Aligned {
+    fn run(&mut self) -> u64 {
+        assert_eq!(self as *const Self as usize % 16, 0);
+        self.0.iter().sum()
+    }
+}
+
+impl Command for String {
+    fn run(&mut self) -> u64 {
+        self.len() as u64
+    }
+}
+
+struct Counted(Arc<AtomicUsize>);
+
+impl Command for Counted {
+    fn run(&mut self) -> u64 {
+        0
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_dyn_entries() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    // Push and pop enough entries to wrap around several times.
+    for i in 0..BUFFER_SIZE as u64 {
+        let mut producer = unsafe { prod.dyn_producer::<dyn Command>() };
+        assert!(producer.push(Small(i as u8), |v| v).is_ok());
+        assert!(producer.push(Aligned([i, 1, 2]), |v| v).is_ok());
+        assert!(producer.push(Counted(drops.clone()), |v| v).is_ok());
+
+        let mut consumer = unsafe { cons.dyn_consumer::<dyn Command>() };
+        assert_eq!(consumer.pop_with(|c| c.run()), Some(i as u8 as u64));
+        assert_eq!(consumer.pop_with(|c| c.run()), Some(i + 3));
+        assert_eq!(consumer.pop_with(|c| c.run()), Some(0));
+        assert_eq!(consumer.pop_with(|c| c.run()), None);
+    }
+
+    assert_eq!(drops.load(Ordering::Relaxed), BUFFER_SIZE);
+    assert_eq!(cons.available(), 0);
+}
+
+struct Big([u8; BUFFER_SIZE]);
+
+impl Command for Big {
+    fn run(&mut self) -> u64 {
+        self.0.len() as u64
+    }
+}
+
+#[test]
+fn test_dyn_entries_full_and_clear() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let mut producer = unsafe { prod.dyn_producer::<dyn Command>() };
+    assert!(producer.push(Big([0; BUFFER_SIZE]), |v| v).is_err());
+
+    let mut pushed = 0;
+    while producer.push(Counted(drops.clone()), |v| v).is_ok() {
+        pushed += 1;
+    }
+    assert!(pushed > 0);
+    assert_eq!(drops.load(Ordering::Relaxed), 1);
+
+    let mut consumer = unsafe { cons.dyn_consumer::<dyn Command>() };
+    assert_eq!(consumer.clear(), pushed);
+    assert_eq!(drops.load(Ordering::Relaxed), pushed + 1);
+}
+
+#[test]
+#[should_panic]
+fn test_dyn_entries_wrong_coercion() {
+    #[repr(C)]
+    struct Outer(u64, Small);
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, _cons) = buf.split();
+
+    let _ = unsafe { prod.dyn_producer::<dyn Command>() }.push(Outer(0, Small(1)), |v| &mut v.1);
+}
+
+#[test]
+fn test_dyn_entries_moved_buffer() {
+    use mutringbuf::{LocalStackRB, StackSplit};
+
+    #[repr(C, align(16))]
+    struct Placed(LocalStackRB<u8, 256>);
+
+    // Shifts the buffer by 8 bytes, so that values aligned to 16 in `Placed` are no longer so.
+    #[repr(C, align(16))]
+    struct Shifted(u64, LocalStackRB<u8, 256>);
+
+    let mut placed = Placed(LocalStackRB::new_zeroed());
+
+    {
+        let (mut prod, _cons) = placed.0.split();
+        let mut producer = unsafe { prod.dyn_producer::<dyn Command>() };
+        assert!(producer.push(Small(7), |v| v).is_ok());
+        assert!(producer.push(Aligned([1, 2, 3]), |v| v).is_ok());
+        assert!(producer.push(String::from("moved"), |v| v).is_ok());
+    }
+
+    // Entries hold no pointer to the buffer, so they can be read from its new location.
+    let mut shifted = Box::new(Shifted(0, placed.0));
+    let (_prod, mut cons) = shifted.1.split();
+    let mut consumer = unsafe { cons.dyn_consumer::<dyn Command>() };
+    assert_eq!(consumer.pop_with(|c| c.run()), Some(7));
+    assert_eq!(consumer.pop_with(|c| c.run()), Some(6));
+    assert_eq!(consumer.pop_with(|c| c.run()), Some(5));
+    assert_eq!(consumer.pop_with(|c| c.run()), None);
+}
@@ -0,0 +1,93 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[test]
+fn test_frames() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    for round in 0..3 {
+        for i in 0..BUFFER_SIZE / 2 {
+            prod.push(i).unwrap();
+        }
+
+        let mut frames = work.frames::<4>();
+        let mut count = 0;
+        while let Some(frame) = frames.next_frame() {
+            assert_eq!(frame[0] % 4, 0);
+            *frame = frame.map(|x| x + 1);
+            count += 1;
+        }
+        assert_eq!(count, BUFFER_SIZE / 2 / 4);
+        drop(frames);
+
+        let mut frames = cons.peek_frames::<4>();
+        for i in 0..BUFFER_SIZE / 2 / 4 {
+            let frame = frames.next_frame().unwrap();
+            assert_eq!(
+                frame,
+                &[4 * i + 1, 4 * i + 2, 4 * i + 3, 4 * i + 4],
+                "{round}"
+            );
+        }
+        assert!(frames.next_frame().is_none());
+    }
+
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_pop_array() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 2 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..5 {
+        prod.push(i).unwrap();
+    }
+
+    assert_eq!(cons.pop_array::<3>(), Some([0, 1, 2]));
+    assert_eq!(cons.pop_array::<3>(), None);
+    assert_eq!(cons.pop_array::<2>(), Some([3, 4]));
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_pop_array_moves_items() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    for _ in 0..4 {
+        prod.push(value.clone()).unwrap();
+    }
+
+    let popped = cons.pop_array::<3>().unwrap();
+    assert_eq!(Rc::strong_count(&value), 5);
+    drop(popped);
+    assert_eq!(Rc::strong_count(&value), 2);
+}
+
+#[cfg(not(feature = "vmem"))]
+#[test]
+#[should_panic]
+fn test_frames_not_aligned() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, _cons) = buf.split_mut();
+
+    prod.push(0).unwrap();
+    unsafe { work.advance(1) };
+
+    work.frames::<4>();
+}
@@ -0,0 +1,189 @@
+use crate::{common_def, get_buf};
+#[cfg(not(feature = "vmem"))]
+use mutringbuf::HeapSplit;
+use mutringbuf::iterators::{CobsDecoder, FrameError, SlipDecoder};
+use mutringbuf::{ConcurrentHeapRB, MRBIterator};
+
+common_def!();
+
+fn collect(pair: mutringbuf::iterators::SlicePair<u8>) -> Vec<u8> {
+    pair.iter().copied().collect()
+}
+
+#[test]
+fn test_read_until() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that frames wrap around.
+    for _ in 0..BUFFER_SIZE - 6 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    prod.push_slice(b"ab;cdef").unwrap();
+
+    let mut frames = cons.read_until(b';');
+    assert_eq!(collect(frames.next_frame().unwrap()), b"ab");
+    assert!(frames.next_frame().is_none());
+    drop(frames);
+
+    prod.push_slice(b"g;;").unwrap();
+
+    let mut frames = cons.read_until(b';');
+    assert_eq!(collect(frames.next_frame().unwrap()), b"cdefg");
+    assert!(frames.next_frame().unwrap().is_empty());
+    assert!(frames.next_frame().is_none());
+    drop(frames);
+
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_lines() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(b"first\r\nsecond\n\nthird").unwrap();
+
+    let mut lines = cons.lines();
+    assert_eq!(collect(lines.next_frame().unwrap()), b"first");
+    assert_eq!(collect(lines.next_frame().unwrap()), b"second");
+    assert!(lines.next_frame().unwrap().is_empty());
+    assert!(lines.next_frame().is_none());
+    drop(lines);
+
+    assert_eq!(cons.available(), 5);
+}
+
+#[test]
+fn test_slip() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+    let mut out = ConcurrentHeapRB::<u8>::new_zeroed(64);
+    let (mut out_prod, mut out_cons) = out.split();
+
+    for _ in 0..BUFFER_SIZE - 4 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    let frame = [1, 0xC0, 2, 0xDB, 3];
+    prod.push_slip_frame(&frame).unwrap();
+    // Empty frame, which is skipped.
+    prod.push(0xC0).unwrap();
+    // Malformed frame.
+    prod.push_slice(&[0xDB, 1, 0xC0]).unwrap();
+    prod.push_slip_frame(&[4]).unwrap();
+
+    let mut decoder = SlipDecoder::new();
+    let mut messages = out_prod.messages();
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(Some(5)));
+    assert_eq!(
+        decoder.decode_frame(&mut cons, &mut messages),
+        Err(FrameError::Malformed)
+    );
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(Some(1)));
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(None));
+
+    // Incomplete frame.
+    prod.push_slice(&[5, 6]).unwrap();
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(None));
+    prod.push(0xC0).unwrap();
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(Some(2)));
+
+    let mut messages = out_cons.messages();
+    assert_eq!(collect(messages.next_message().unwrap().payload()), frame);
+    assert_eq!(collect(messages.next_message().unwrap().payload()), [4]);
+    assert_eq!(collect(messages.next_message().unwrap().payload()), [5, 6]);
+    assert!(messages.next_message().is_none());
+}
+
+#[test]
+fn test_cobs() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+    let mut out = ConcurrentHeapRB::<u8>::new_zeroed(BUFFER_SIZE);
+    let (mut out_prod, mut out_cons) = out.split();
+
+    let frames: [Vec<u8>; 5] = [
+        vec![],
+        vec![0],
+        vec![1, 0, 0, 2, 3, 0],
+        (1..=254).collect(),
+        (0..300).map(|i| (i % 255) as u8 + 1).collect(),
+    ];
+
+    let mut decoder = CobsDecoder::new();
+    let mut messages = out_prod.messages();
+
+    for frame in &frames {
+        prod.push_cobs_frame(frame).unwrap();
+        assert_eq!(
+            decoder.decode_frame(&mut cons, &mut messages),
+            Ok(Some(frame.len()))
+        );
+
+        let mut consumer = out_cons.messages();
+        assert_eq!(&collect(consumer.next_message().unwrap().payload()), frame);
+    }
+
+    // Encoded frames.
+    for (frame, encoded) in [
+        (
+            vec![0x11, 0x22, 0x00, 0x33],
+            vec![3, 0x11, 0x22, 2, 0x33, 0],
+        ),
+        (vec![0, 0], vec![1, 1, 1, 0]),
+        (
+            (1..=254).collect(),
+            [0xFF].into_iter().chain(1..=254).chain([0]).collect(),
+        ),
+    ] {
+        prod.push_cobs_frame(&frame).unwrap();
+        let mut dst = vec![0; encoded.len()];
+        cons.copy_slice(&mut dst).unwrap();
+        assert_eq!(dst, encoded);
+    }
+
+    // Malformed frame: the code overruns the delimiter.
+    prod.push_slice(&[5, 1, 0]).unwrap();
+    assert_eq!(
+        decoder.decode_frame(&mut cons, &mut messages),
+        Err(FrameError::Malformed)
+    );
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_decode_full() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+    // Payloads up to 7 bytes.
+    let mut out = ConcurrentHeapRB::<u8>::new_zeroed(16);
+    let (mut out_prod, mut out_cons) = out.split();
+
+    prod.push_cobs_frame(&[1; 8]).unwrap();
+    prod.push_cobs_frame(&[2; 4]).unwrap();
+    prod.push_cobs_frame(&[3; 2]).unwrap();
+
+    let mut decoder = CobsDecoder::new();
+    let mut messages = out_prod.messages();
+
+    assert_eq!(
+        decoder.decode_frame(&mut cons, &mut messages),
+        Err(FrameError::TooLarge)
+    );
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(Some(4)));
+    assert_eq!(
+        decoder.decode_frame(&mut cons, &mut messages),
+        Err(FrameError::Full)
+    );
+
+    let mut consumer = out_cons.messages();
+    assert_eq!(collect(consumer.next_message().unwrap().payload()), [2; 4]);
+    drop(consumer);
+
+    assert_eq!(decoder.decode_frame(&mut cons, &mut messages), Ok(Some(2)));
+    assert_eq!(cons.available(), 0);
+}
@@ -0,0 +1,59 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+use mutringbuf::iterators::SlicePair;
+
+common_def!();
+
+fn slice_to_vec(slice: SlicePair<usize>) -> Vec<usize> {
+    slice.iter().copied().collect()
+}
+
+#[test]
+fn test_history() {
+    let mut buf = get_buf!(Concurrent).with_history(3);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 1 - 3);
+    assert!(cons.history(1).is_none());
+
+    for i in 0..BUFFER_SIZE * 2 {
+        prod.push(i).unwrap();
+        assert_eq!(cons.pop(), Some(i));
+
+        let expected = (i + 1).min(3);
+        assert_eq!(
+            slice_to_vec(cons.history(expected).unwrap()),
+            (i + 1 - expected..=i).collect::<Vec<_>>()
+        );
+    }
+
+    // History cannot exceed the configured length.
+    assert!(cons.history(4).is_none());
+    assert_eq!(slice_to_vec(cons.history(0).unwrap()), []);
+}
+
+#[test]
+fn test_history_reserves_space() {
+    let mut buf = get_buf!(Concurrent).with_history(3);
+    let (mut prod, mut cons) = buf.split();
+
+    for i in 0..BUFFER_SIZE - 4 {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(prod.push(0), Err(0));
+
+    // Consuming an item frees a location, while the last three consumed items are retained.
+    assert_eq!(cons.pop(), Some(0));
+    assert_eq!(prod.available(), 1);
+    prod.push(0).unwrap();
+
+    unsafe { cons.advance(4) };
+    assert_eq!(slice_to_vec(cons.history(3).unwrap()), [2, 3, 4]);
+    assert_eq!(prod.available(), 4);
+}
+
+#[test]
+#[should_panic]
+fn test_history_too_long() {
+    let _ = get_buf!(Concurrent).with_history(BUFFER_SIZE - 1);
+}
@@ -1,7 +1,7 @@
 extern crate alloc;
 
 use crate::{common_def, get_buf};
-use mutringbuf::MRBIterator;
+use mutringbuf::{FollowerIter, MRBIterator};
 
 common_def!();
 
@@ -57,8 +57,8 @@ fn test_push_work_pop_slice() {
     assert_eq!(work.available(), BUFFER_SIZE - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some((h, t)) = work.get_workable_slice_exact(BUFFER_SIZE - 1) {
-        for i in h.iter_mut().chain(t) {
+    if let Some(mut slices) = work.get_workable_slice_exact(BUFFER_SIZE - 1) {
+        for i in slices.iter_mut() {
             *i += 1;
         }
         unsafe { work.advance(BUFFER_SIZE - 1) };
@@ -68,8 +68,8 @@ fn test_push_work_pop_slice() {
     assert_eq!(work.available(), 0);
     assert_eq!(cons.available(), BUFFER_SIZE - 1);
 
-    if let Some((h, t)) = cons.peek_slice(BUFFER_SIZE - 1) {
-        for (consumed, i) in [h, t].concat().iter().zip(slice) {
+    if let Some(slices) = cons.peek_slice(BUFFER_SIZE - 1) {
+        for (consumed, i) in slices.iter().zip(slice) {
             assert_eq!(*consumed, i + 1);
         }
     }
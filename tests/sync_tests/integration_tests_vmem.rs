@@ -1,7 +1,7 @@
 extern crate alloc;
 
 use crate::{common_def, get_buf};
-use mutringbuf::{ConcurrentHeapRB, MRBIterator};
+use mutringbuf::{ConcurrentHeapRB, FollowerIter, MRBIterator};
 
 common_def!();
 
@@ -0,0 +1,136 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+/// Moves indices near the end of the buffer, so that the next writes wrap around.
+macro_rules! move_near_end {
+    ($prod: expr, $cons: expr, $left: expr) => {
+        for _ in 0..BUFFER_SIZE - $left {
+            $prod.push(0u8).unwrap();
+        }
+        $cons.reset_index();
+    };
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_from_write_to() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    move_near_end!(prod, cons, 4);
+
+    let src: Vec<u8> = (0..10).collect();
+    let mut reader = &src[..];
+
+    let mut read = 0;
+    while read < src.len() {
+        read += prod.read_from(&mut reader, 10 - read).unwrap();
+    }
+    assert_eq!(cons.available(), 10);
+
+    let mut dst = Vec::new();
+    assert_eq!(cons.write_to(&mut dst, 3).unwrap(), 3);
+    while cons.available() > 0 {
+        cons.write_to(&mut dst, usize::MAX).unwrap();
+    }
+    assert_eq!(dst, src);
+
+    // Nothing left to write.
+    assert_eq!(cons.write_to(&mut dst, 10).unwrap(), 0);
+    // End of file.
+    assert_eq!(prod.read_from(&mut &[][..], 10).unwrap(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_from_full() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, _cons) = buf.split();
+
+    while prod.push(0).is_ok() {}
+
+    assert_eq!(prod.read_from(&mut &[1, 2, 3][..], 3).unwrap(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_lying_reader_writer() {
+    use std::io::{ErrorKind, Read, Write};
+
+    /// Claims to have transferred far more bytes than it has been given.
+    struct Liar;
+
+    impl Read for Liar {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Ok(100_000)
+        }
+    }
+
+    impl Write for Liar {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Ok(100_000)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    let err = prod.read_from(&mut Liar, 16).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(prod.index(), 0);
+    assert_eq!(cons.available(), 0);
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+
+    let err = cons.write_to(&mut Liar, 16).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(cons.available(), 3);
+    assert_eq!(cons.peek_available().unwrap().len(), 3);
+}
+
+#[cfg(all(unix, feature = "fd"))]
+#[test]
+fn test_fd() {
+    use mutringbuf::iterators::FdError;
+
+    let mut fds = [0; 2];
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    let [r, w] = fds;
+    unsafe { libc::fcntl(r, libc::F_SETFL, libc::O_NONBLOCK) };
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    move_near_end!(prod, cons, 3);
+    for i in 0..8 {
+        prod.push(i).unwrap();
+    }
+
+    // Empty pipe.
+    assert_eq!(prod.read_from_fd(r, 8), Err(FdError::WouldBlock));
+
+    assert_eq!(cons.write_to_fd(w, 8), Ok(8));
+    assert_eq!(cons.available(), 0);
+    assert_eq!(cons.write_to_fd(w, 8), Ok(0));
+
+    assert_eq!(prod.read_from_fd(r, 5), Ok(5));
+    assert_eq!(prod.read_from_fd(r, 5), Ok(3));
+
+    let mut out = [0; 8];
+    cons.copy_slice(&mut out).unwrap();
+    assert_eq!(out, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // Invalid descriptor.
+    assert_eq!(prod.read_from_fd(-1, 1), Err(FdError::Os(libc::EBADF)));
+
+    unsafe {
+        libc::close(r);
+        libc::close(w);
+    }
+}
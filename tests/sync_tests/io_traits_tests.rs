@@ -0,0 +1,102 @@
+use crate::{common_def, get_buf};
+
+common_def!();
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_buf() {
+    use bytes::{Buf, BufMut};
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 4 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    assert_eq!(prod.remaining_mut(), BUFFER_SIZE - 1);
+    prod.put_slice(&[1, 2, 3, 4, 5, 6]);
+    prod.put_u16(0x0708);
+    assert_eq!(prod.remaining_mut(), BUFFER_SIZE - 9);
+
+    assert_eq!(cons.remaining(), 8);
+    #[cfg(not(feature = "vmem"))]
+    assert_eq!(cons.chunk(), &[1, 2, 3, 4]);
+    #[cfg(feature = "vmem")]
+    assert_eq!(cons.chunk(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+    #[cfg(feature = "std")]
+    {
+        let mut dst = [std::io::IoSlice::new(&[]); 2];
+        let count = cons.chunks_vectored(&mut dst);
+        let bytes: Vec<u8> = dst[..count].iter().flat_map(|s| s.to_vec()).collect();
+        assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    assert_eq!(cons.get_u8(), 1);
+    Buf::advance(&mut cons, 1);
+    let mut dst = [0; 4];
+    cons.copy_to_slice(&mut dst);
+    assert_eq!(dst, [3, 4, 5, 6]);
+    assert_eq!(cons.get_u16(), 0x0708);
+    assert!(!cons.has_remaining());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+#[should_panic]
+fn test_buf_advance_past_remaining() {
+    use bytes::Buf;
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push(0u8).unwrap();
+    Buf::advance(&mut cons, 2);
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn test_embedded_io() {
+    use embedded_io::{BufRead, Read, ReadReady, Write, WriteReady};
+    use mutringbuf::MRBIterator;
+
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    for _ in 0..BUFFER_SIZE - 4 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    assert_eq!(cons.read_ready(), Ok(false));
+    assert_eq!(prod.write_ready(), Ok(true));
+
+    assert_eq!(prod.write(&[]), Ok(0));
+    prod.write_all(&[1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(cons.read_ready(), Ok(true));
+
+    let mut dst = [0; 3];
+    assert_eq!(cons.read(&mut dst), Ok(3));
+    assert_eq!(dst, [1, 2, 3]);
+
+    let chunk = cons.fill_buf().unwrap();
+    #[cfg(not(feature = "vmem"))]
+    assert_eq!(chunk, &[4]);
+    #[cfg(feature = "vmem")]
+    assert_eq!(chunk, &[4, 5, 6]);
+    cons.consume(1);
+
+    let mut dst = [0; 8];
+    assert_eq!(cons.read(&mut dst), Ok(2));
+    assert_eq!(dst[..2], [5, 6]);
+    assert_eq!(cons.read(&mut []), Ok(0));
+
+    // Fill the buffer.
+    let free = prod.available();
+    assert_eq!(prod.write(&vec![0; free + 1]), Ok(free));
+    assert_eq!(prod.write_ready(), Ok(false));
+    prod.flush().unwrap();
+}
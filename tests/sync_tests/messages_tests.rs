@@ -0,0 +1,97 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+use mutringbuf::iterators::{MESSAGE_HEADER_LEN, MessageError};
+
+common_def!();
+
+#[test]
+fn test_messages() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that the second payload wraps around.
+    for _ in 0..BUFFER_SIZE - 22 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    let mut producer = prod.messages();
+    producer.push(&[1, 2, 3]).unwrap();
+    producer.push_tagged(7, &[4, 5, 6, 7, 8, 9]).unwrap();
+    producer.push(&[]).unwrap();
+
+    let mut consumer = cons.messages();
+
+    let msg = consumer.next_message().unwrap();
+    assert_eq!(msg.tag(), 0);
+    assert_eq!(msg.payload().iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let msg = consumer.next_message().unwrap();
+    assert_eq!(msg.tag(), 7);
+    assert_eq!(
+        msg.payload().iter().copied().collect::<Vec<_>>(),
+        [4, 5, 6, 7, 8, 9]
+    );
+    #[cfg(not(feature = "vmem"))]
+    assert!(msg.payload().as_contiguous().is_none());
+    #[cfg(feature = "vmem")]
+    assert!(msg.payload().as_contiguous().is_some());
+
+    assert!(consumer.next_message().unwrap().payload().is_empty());
+    assert!(consumer.next_message().is_none());
+    drop(consumer);
+
+    assert_eq!(cons.available(), 0);
+}
+
+#[test]
+fn test_messages_errors() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut producer = prod.messages();
+    let max = producer.max_payload_len();
+    assert_eq!(max, BUFFER_SIZE - 1 - MESSAGE_HEADER_LEN);
+
+    let payload = vec![1; max + 1];
+    assert_eq!(producer.push(&payload), Err(MessageError::TooLarge));
+
+    producer.push(&payload[..max]).unwrap();
+    assert_eq!(producer.push(&[]), Err(MessageError::Full));
+
+    let mut consumer = cons.messages();
+    assert_eq!(consumer.next_message().unwrap().payload().len(), max);
+    assert!(consumer.next_message().is_none());
+
+    drop(consumer);
+    prod.messages().push(&[]).unwrap();
+}
+
+#[test]
+fn test_messages_padding() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    for (left, len) in [(12, 6), (4, 2)] {
+        for _ in 0..BUFFER_SIZE - left - prod.index() {
+            prod.push(0u8).unwrap();
+        }
+        cons.reset_index();
+
+        let mut producer = prod.messages().with_padding();
+        producer.push_tagged(1, &vec![2; len]).unwrap();
+        producer.push_tagged(3, &[4; 16]).unwrap();
+
+        let mut consumer = cons.messages().with_padding();
+
+        let msg = consumer.next_message().unwrap();
+        assert_eq!(msg.tag(), 1);
+        assert_eq!(msg.payload().as_contiguous(), Some(&vec![2; len][..]));
+
+        let msg = consumer.next_message().unwrap();
+        assert_eq!(msg.tag(), 3);
+        assert_eq!(msg.payload().as_contiguous(), Some(&[4; 16][..]));
+
+        assert!(consumer.next_message().is_none());
+    }
+}
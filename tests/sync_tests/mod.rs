@@ -1,18 +1,46 @@
 #![cfg(feature = "alloc")]
 
+pub mod bip_tests;
 pub mod concurrent_fib;
 #[cfg(not(feature = "vmem"))]
 pub mod cons_tests;
 #[cfg(feature = "vmem")]
 pub mod cons_tests_vmem;
+pub mod contiguous_tests;
+#[cfg(all(any(target_os = "linux", target_os = "android"), feature = "fd"))]
+pub mod datagram_tests;
+pub mod detached_search_tests;
+pub mod detached_transactions_tests;
 pub mod detached_work_tests;
+pub mod diagnostics_tests;
 pub mod drop;
+pub mod dyn_entries_tests;
+pub mod frames_tests;
+pub mod framing_tests;
+pub mod history_tests;
 #[cfg(not(feature = "vmem"))]
 pub mod integration_tests;
 #[cfg(feature = "vmem")]
 pub mod integration_tests_vmem;
+#[cfg(any(feature = "std", feature = "fd"))]
+pub mod io_tests;
+#[cfg(any(feature = "bytes", feature = "embedded-io"))]
+pub mod io_traits_tests;
+pub mod messages_tests;
 pub mod multithreading;
+#[cfg(feature = "bytemuck")]
+pub mod pod_tests;
+pub mod position_tests;
 pub mod prod_tests;
+pub mod slice_pair_tests;
+#[cfg(feature = "stats")]
+pub mod stats_tests;
+pub mod uninit_rb_tests;
+pub mod uninit_write_tests;
+#[cfg(feature = "vmem")]
+pub mod vmem_tests;
+pub mod window_tests;
+pub mod windows_tests;
 #[cfg(not(feature = "vmem"))]
 pub mod work_tests;
 #[cfg(feature = "vmem")]
@@ -0,0 +1,64 @@
+use crate::{common_def, get_buf};
+use bytemuck::{Pod, Zeroable};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Record {
+    id: u32,
+    flags: u16,
+    kind: u16,
+    value: f64,
+}
+
+unsafe impl Zeroable for Record {}
+unsafe impl Pod for Record {}
+
+#[test]
+fn test_pod_mixed_with_bytes() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that records wrap around.
+    for _ in 0..BUFFER_SIZE - 7 {
+        prod.push(0u8).unwrap();
+    }
+    cons.reset_index();
+
+    let record = Record {
+        id: 1,
+        flags: 2,
+        kind: 3,
+        value: 4.5,
+    };
+
+    prod.push_pod(&0xabu8).unwrap();
+    prod.push_pod(&record).unwrap();
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    prod.push_pod(&0x0102_0304u32).unwrap();
+
+    assert_eq!(cons.pop_pod::<u8>(), Some(0xab));
+    assert_eq!(cons.peek_pod::<Record>(), Some(record));
+    assert_eq!(cons.available(), size_of::<Record>() + 7);
+    assert_eq!(cons.pop_pod::<Record>(), Some(record));
+
+    assert_eq!(cons.pop_pod::<[u8; 3]>(), Some([1, 2, 3]));
+    assert_eq!(cons.pop_pod::<u32>(), Some(0x0102_0304));
+    assert_eq!(cons.pop_pod::<u8>(), None);
+}
+
+#[test]
+fn test_pod_not_enough_bytes() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(cons.peek_pod::<u32>(), None);
+    assert_eq!(cons.pop_pod::<u32>(), None);
+    assert_eq!(cons.available(), 3);
+
+    while prod.push_pod(&0u64).is_some() {}
+    assert!(prod.available() < size_of::<u64>());
+}
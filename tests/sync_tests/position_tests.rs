@@ -0,0 +1,98 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[test]
+fn test_positions_laps() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    let chunk = BUFFER_SIZE / 3;
+    let mut total = 0u64;
+
+    for _ in 0..10 {
+        for _ in 0..chunk {
+            prod.push(1).unwrap();
+        }
+        unsafe { work.advance(chunk) };
+        unsafe { cons.advance(chunk / 2) };
+        total += chunk as u64;
+
+        let popped = cons.position();
+
+        for it in [
+            prod.total_pushed(),
+            work.total_pushed(),
+            cons.total_pushed(),
+        ] {
+            assert_eq!(it, total);
+        }
+        for it in [
+            prod.total_worked(),
+            work.total_worked(),
+            cons.total_worked(),
+        ] {
+            assert_eq!(it, total);
+        }
+        for it in [
+            prod.total_popped(),
+            work.total_popped(),
+            cons.total_popped(),
+        ] {
+            assert_eq!(it, popped);
+        }
+
+        unsafe { cons.advance(chunk - chunk / 2) };
+    }
+
+    assert_eq!(prod.position(), total);
+    assert_eq!(work.position(), total);
+    assert_eq!(cons.position(), total);
+    assert!(total > BUFFER_SIZE as u64 * 3);
+}
+
+#[test]
+fn test_positions_detached() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, work, mut cons) = buf.split_mut();
+
+    for _ in 0..BUFFER_SIZE - 1 {
+        prod.push(1).unwrap();
+    }
+
+    let mut work = work.detach();
+    unsafe { work.advance(10) };
+
+    // The local position moves, while the global one does not.
+    assert_eq!(work.position(), 10);
+    assert_eq!(work.total_worked(), 0);
+    assert_eq!(prod.total_worked(), 0);
+
+    unsafe { work.go_back(5) };
+    assert_eq!(work.position(), 5);
+
+    work.sync_index();
+    assert_eq!(work.total_worked(), 5);
+    assert_eq!(prod.total_worked(), 5);
+
+    let idx = work.index();
+    unsafe { work.set_index(idx + 20) };
+    assert_eq!(work.position(), 25);
+    unsafe { work.set_index(idx + 2) };
+    assert_eq!(work.position(), 7);
+    assert_eq!(work.total_worked(), 5);
+
+    work.reset_index();
+    assert_eq!(work.position(), BUFFER_SIZE as u64 - 1);
+
+    let mut work = work.attach();
+    assert_eq!(cons.total_worked(), BUFFER_SIZE as u64 - 1);
+
+    unsafe { cons.advance(BUFFER_SIZE - 1) };
+    prod.push(1).unwrap();
+    unsafe { work.advance(1) };
+
+    assert_eq!(cons.total_pushed(), BUFFER_SIZE as u64);
+    assert_eq!(prod.total_popped(), BUFFER_SIZE as u64 - 1);
+}
@@ -0,0 +1,137 @@
+use crate::{common_def, get_buf};
+use mutringbuf::{FollowerIter, MRBIterator};
+
+common_def!();
+
+#[test]
+fn test_slice_pair_wrapping() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    let slices = cons.peek_available().unwrap();
+    assert_eq!(slices.len(), 6);
+    assert_eq!(
+        slices.iter().copied().collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4, 5]
+    );
+
+    #[cfg(not(feature = "vmem"))]
+    {
+        assert!(slices.as_contiguous().is_none());
+        assert_eq!(slices.as_slices(), (&[0, 1, 2][..], &[3, 4, 5][..]));
+    }
+    #[cfg(feature = "vmem")]
+    assert_eq!(slices.as_contiguous(), Some(&[0, 1, 2, 3, 4, 5][..]));
+
+    let mut dst = [0; 6];
+    slices.copy_to_slice(&mut dst);
+    assert_eq!(dst, [0, 1, 2, 3, 4, 5]);
+
+    for mid in 0..=6 {
+        let (l, r) = slices.split_at(mid);
+        assert_eq!(l.iter().copied().collect::<Vec<_>>(), dst[..mid]);
+        assert_eq!(r.iter().copied().collect::<Vec<_>>(), dst[mid..]);
+    }
+}
+
+#[test]
+fn test_slice_pair_mut() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    for _ in 0..BUFFER_SIZE - 2 {
+        prod.push(0).unwrap();
+    }
+    work.reset_index();
+    cons.reset_index();
+
+    for _ in 0..4 {
+        prod.push(0).unwrap();
+    }
+
+    let mut slices = work.get_workable_slice_exact(4).unwrap();
+    slices.copy_from_slice(&[1, 2, 3, 4]);
+    slices.iter_mut().for_each(|x| *x *= 10);
+
+    let (mut l, r) = slices.split_at(1);
+    assert_eq!(l.as_contiguous_mut(), Some(&mut [10][..]));
+    assert_eq!(
+        r.into_pair().iter().copied().collect::<Vec<_>>(),
+        [20, 30, 40]
+    );
+    unsafe { work.advance(4) };
+
+    let mut dst = [0; 4];
+    cons.peek_slice(4).unwrap().copy_to_slice(&mut dst);
+    assert_eq!(dst, [10, 20, 30, 40]);
+}
+
+#[test]
+#[should_panic]
+fn test_slice_pair_copy_len_mismatch() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    cons.peek_available().unwrap().copy_to_slice(&mut [0; 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_slice_pair_split_out_of_bounds() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[1, 2, 3]).unwrap();
+    let _ = cons.peek_available().unwrap().split_at(4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_slice_pair_io_slices() {
+    use mutringbuf::{ConcurrentHeapRB, HeapSplit};
+    use std::io::{Read, Write};
+
+    let buf = ConcurrentHeapRB::<u8>::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+    let len = prod.buf_len();
+
+    for _ in 0..len - 2 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+    prod.push_slice(b"hello").unwrap();
+
+    let mut out = Vec::new();
+    let written = out
+        .write_vectored(&cons.peek_available().unwrap().as_io_slices())
+        .unwrap();
+    assert_eq!(&out[..written], b"hello");
+    unsafe { cons.advance(5) };
+
+    let mut slots = unsafe { prod.get_next_slices_mut(5) }.unwrap();
+    let read = (&b"world"[..])
+        .read_vectored(&mut slots.as_io_slices_mut())
+        .unwrap();
+    assert_eq!(read, 5);
+    unsafe { prod.advance(5) };
+
+    assert_eq!(
+        cons.peek_available()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        b"world"
+    );
+}
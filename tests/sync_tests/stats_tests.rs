@@ -0,0 +1,127 @@
+use crate::{common_def, get_buf};
+use mutringbuf::{ConcurrentHeapRB, FollowerIter, MRBIterator};
+
+common_def!();
+
+#[test]
+fn test_stats() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    assert_eq!(prod.stats(), Default::default());
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+    for _ in 0..4 {
+        unsafe { work.advance(1) };
+    }
+    for _ in 0..3 {
+        cons.pop().unwrap();
+    }
+
+    assert!(cons.pop().is_some());
+    assert!(cons.pop().is_none());
+    assert!(work.get_workable_slice_exact(7).is_none());
+
+    for _ in 0..BUFFER_SIZE {
+        let _ = prod.push(0);
+    }
+
+    let stats = cons.stats();
+    assert_eq!(stats, work.stats());
+    assert_eq!(stats.pushed, 10 + BUFFER_SIZE - 1 - 6);
+    assert_eq!(stats.worked, 4);
+    assert_eq!(stats.popped, 4);
+    assert_eq!(stats.failed_pushes, 7);
+    assert_eq!(stats.failed_works, 1);
+    assert_eq!(stats.failed_pops, 1);
+    assert_eq!(stats.high_water_mark, BUFFER_SIZE - 1);
+    assert_eq!(stats.cons_wakeups, 0);
+}
+
+#[test]
+fn test_stats_detached() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+    let mut cons = cons.detach();
+
+    prod.push_slice(&[1; 8]).unwrap();
+    unsafe { cons.advance(5) };
+    assert_eq!(prod.stats().popped, 0);
+
+    cons.sync_index();
+    assert_eq!(prod.stats().popped, 5);
+    assert_eq!(prod.stats().high_water_mark, 8);
+}
+
+#[test]
+fn test_stats_handle() {
+    use mutringbuf::HeapSplit;
+
+    let buf = ConcurrentHeapRB::<u32>::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    let handle = prod.stats_handle();
+    assert_eq!(prod.alive_iters(), 2);
+
+    let reader = std::thread::spawn(move || {
+        let handle = handle.clone();
+        while handle.snapshot().popped < 100 {}
+        handle
+    });
+
+    for i in 0..100 {
+        prod.push(i).unwrap();
+        assert_eq!(cons.pop(), Some(i));
+    }
+
+    let handle = reader.join().unwrap();
+
+    // The handle keeps the statistics alive, even once the buffer has been dropped.
+    drop(prod);
+    drop(cons);
+    let stats = handle.snapshot();
+    assert_eq!((stats.pushed, stats.popped), (100, 100));
+    assert_eq!(stats.high_water_mark, 1);
+}
+
+#[test]
+fn test_stats_handles_do_not_hold_buffer() {
+    use mutringbuf::HeapSplit;
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentHeapRB::<Rc<i32>>::new_uninit(BUFFER_SIZE).fill(value.clone());
+    let (prod, cons) = buf.split();
+
+    // More handles than an iterator counter could hold.
+    let handles: Vec<_> = (0..300).map(|_| cons.stats_handle()).collect();
+    assert_eq!(prod.alive_iters(), 2);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+    assert!(handles.iter().all(|h| h.snapshot().popped == 0));
+}
+
+#[test]
+fn test_stats_peeks_not_recorded() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[1; 3]).unwrap();
+
+    // Peeks and probes beyond the available items are not failed pops.
+    assert!(cons.peek_at(5).is_none());
+    assert!(cons.peek_slice(4).is_none());
+    assert_eq!(cons.stats().failed_pops, 0);
+
+    cons.pop_array::<3>().unwrap();
+    assert!(cons.peek_ref().is_none());
+    assert_eq!(cons.stats().failed_pops, 0);
+
+    assert!(cons.pop().is_none());
+    assert_eq!(cons.stats().failed_pops, 1);
+}
@@ -0,0 +1,115 @@
+use crate::common_def;
+use mutringbuf::{ConcurrentHeapRB, FollowerIter, HeapSplit, LocalHeapRB, MRBIterator};
+use std::rc::Rc;
+
+common_def!(buf);
+
+#[test]
+fn test_uninit_split() {
+    let buf = ConcurrentHeapRB::new_uninit(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+
+    for i in 0..BUFFER_SIZE / 2 {
+        prod.push(i).unwrap();
+    }
+    assert!(prod.push_with(|slot| slot.write(0)).is_ok());
+    assert_eq!(prod.available(), BUFFER_SIZE - 2 - BUFFER_SIZE / 2);
+
+    for i in 0..BUFFER_SIZE / 2 {
+        assert_eq!(cons.pop(), Some(i));
+    }
+    assert_eq!(cons.pop(), Some(0));
+    assert_eq!(cons.pop(), None);
+}
+
+#[test]
+fn test_uninit_into_init() {
+    let value = Rc::new(0);
+
+    let buf = LocalHeapRB::new_uninit(BUFFER_SIZE);
+    let (prod, work, cons) = buf.split_mut();
+
+    let mut prod = prod.into_init();
+    prod.push(value.clone()).unwrap();
+
+    drop(prod);
+    drop(work);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let buf = ConcurrentHeapRB::new_uninit(BUFFER_SIZE).into_init();
+    let (mut prod, cons) = buf.split();
+
+    for _ in 0..BUFFER_SIZE - 1 {
+        prod.push(value.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), BUFFER_SIZE);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_uninit_stack() {
+    use mutringbuf::ConcurrentStackRB;
+
+    let mut buf = ConcurrentStackRB::<String, BUFFER_SIZE>::new_uninit();
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice_clone(&[String::from("a"), String::from("b")])
+        .unwrap();
+
+    let mut slots = prod.reserve_uninit(1).unwrap();
+    slots.write(String::from("c")).unwrap();
+    slots.commit();
+
+    assert_eq!(cons.pop().as_deref(), Some("a"));
+    assert_eq!(cons.pop().as_deref(), Some("b"));
+    assert_eq!(cons.pop().as_deref(), Some("c"));
+}
+
+#[test]
+fn test_uninit_fill() {
+    let value = Rc::new(0);
+
+    let buf = LocalHeapRB::new_uninit(BUFFER_SIZE).fill(value.clone());
+    assert_eq!(Rc::strong_count(&value), BUFFER_SIZE);
+
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+    assert_eq!(prod.available(), 0);
+    assert_eq!(work.available(), BUFFER_SIZE - 1);
+    assert_eq!(prod.total_pushed(), BUFFER_SIZE as u64 - 1);
+
+    *work.get_workable().unwrap() = Rc::new(1);
+    unsafe { work.advance(1) };
+    assert_eq!(cons.available(), 1);
+    assert_eq!(cons.pop().as_deref(), Some(&1));
+
+    assert_eq!(prod.available(), 1);
+    prod.push(value.clone()).unwrap();
+
+    drop(prod);
+    drop(work);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_uninit_fill_with() {
+    use mutringbuf::{ConcurrentStackRB, StackSplit};
+
+    let mut i = 0;
+    let mut buf = ConcurrentStackRB::<usize, BUFFER_SIZE>::new_uninit().fill_with(|| {
+        i += 1;
+        i
+    });
+    let (_prod, mut cons) = buf.split();
+
+    for i in 1..BUFFER_SIZE {
+        assert_eq!(cons.pop(), Some(i));
+    }
+    assert_eq!(cons.pop(), None);
+}
@@ -0,0 +1,141 @@
+use crate::common_def;
+use mutringbuf::{ConcurrentHeapRB, HeapSplit, MRBIterator};
+use std::rc::Rc;
+
+common_def!(buf);
+
+#[test]
+fn test_push_with() {
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    let value = Rc::new(0usize);
+
+    for _ in 0..BUFFER_SIZE - 1 {
+        assert!(prod.push_with(|slot| slot.write(value.clone())).is_ok());
+    }
+    assert!(prod.push_with(|slot| slot.write(value.clone())).is_err());
+    assert_eq!(Rc::strong_count(&value), BUFFER_SIZE);
+
+    for _ in 0..BUFFER_SIZE / 2 {
+        cons.pop().unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), BUFFER_SIZE - BUFFER_SIZE / 2);
+
+    for _ in 0..BUFFER_SIZE / 2 {
+        assert!(prod.push_with(|slot| slot.write(value.clone())).is_ok());
+    }
+    assert_eq!(Rc::strong_count(&value), BUFFER_SIZE);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_push_with_foreign_ref() {
+    let buf = ConcurrentHeapRB::<usize>::new_zeroed(BUFFER_SIZE);
+    let (mut prod, _cons) = buf.split();
+
+    let _ = prod.push_with(|_| Box::leak(Box::new(0)));
+}
+
+#[test]
+fn test_reserve_uninit_commit() {
+    let buf = ConcurrentHeapRB::from(vec![0; BUFFER_SIZE]);
+    let (mut prod, mut cons) = buf.split();
+
+    assert!(prod.reserve_uninit(BUFFER_SIZE).is_none());
+
+    let mut slots = prod.reserve_uninit(10).unwrap();
+    assert_eq!(slots.len(), 10);
+
+    for i in 0..5 {
+        slots.write(i).unwrap();
+    }
+    assert_eq!(slots.initialised(), 5);
+    assert_eq!(slots.commit(), 5);
+
+    assert_eq!(prod.available(), BUFFER_SIZE - 6);
+    assert_eq!(cons.available(), 5);
+
+    for i in 0..5 {
+        assert_eq!(cons.pop(), Some(i));
+    }
+}
+
+#[test]
+fn test_reserve_uninit_slices() {
+    let buf = ConcurrentHeapRB::from(vec![0; BUFFER_SIZE]);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; BUFFER_SIZE / 2]).unwrap();
+    unsafe { cons.advance(BUFFER_SIZE / 2) };
+
+    // Wraps around the end of the buffer.
+    let count = BUFFER_SIZE - 1;
+    let mut slots = prod.reserve_uninit(count).unwrap();
+
+    let mut slices = slots.as_mut_slices();
+    assert_eq!(slices.len(), count);
+    for (i, s) in slices.iter_mut().enumerate() {
+        s.write(i);
+    }
+
+    unsafe { slots.assume_init(count) };
+    assert_eq!(slots.commit(), count);
+
+    for i in 0..count {
+        assert_eq!(cons.pop(), Some(i));
+    }
+}
+
+#[test]
+fn test_reserve_uninit_drop() {
+    let value = Rc::new(0usize);
+
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, cons) = buf.split();
+
+    let mut slots = prod.reserve_uninit(10).unwrap();
+
+    slots.write(value.clone()).unwrap();
+    slots.write(value.clone()).unwrap();
+    assert_eq!(Rc::strong_count(&value), 3);
+
+    // Uncommitted values are dropped along with the guard.
+    drop(slots);
+    assert_eq!(Rc::strong_count(&value), 1);
+    assert_eq!(prod.available(), BUFFER_SIZE - 1);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_reserve_uninit_assume_fewer() {
+    let value = Rc::new(0usize);
+
+    let buf = ConcurrentHeapRB::new_zeroed(BUFFER_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    let mut slots = prod.reserve_uninit(10).unwrap();
+
+    for _ in 0..5 {
+        slots.write(value.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&value), 6);
+
+    // Items past `count` are dropped rather than leaked.
+    unsafe { slots.assume_init(2) };
+    assert_eq!(Rc::strong_count(&value), 3);
+    assert_eq!(slots.commit(), 2);
+
+    assert_eq!(cons.available(), 2);
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,102 @@
+use mutringbuf::{ConcurrentHeapRB, ConcurrentStackRB, ConcurrentVmemRB, MRBIterator};
+use mutringbuf::{HeapSplit, StackSplit};
+
+fn push_wrapping<I: MRBIterator<Item = usize>>(
+    prod: &mut mutringbuf::iterators::ProdIter<impl mutringbuf::MutRB<Item = usize>>,
+    cons: &mut I,
+) {
+    let len = prod.buf_len();
+
+    for _ in 0..len - 2 {
+        prod.push(0).unwrap();
+    }
+    unsafe { cons.advance(len - 2) };
+
+    for i in 0..4 {
+        prod.push(i).unwrap();
+    }
+}
+
+#[test]
+fn test_vmem_mapped() {
+    let len = mutringbuf::vmem_helper::get_page_len_mul::<usize>(10);
+    let buf = ConcurrentVmemRB::<usize>::new_zeroed(len);
+    assert!(buf.is_mapped());
+
+    let (mut prod, mut cons) = buf.split();
+    assert!(prod.is_double_mapped());
+    assert_eq!(cons.buf_len(), len);
+
+    push_wrapping(&mut prod, &mut cons);
+
+    let slices = cons.peek_available().unwrap();
+    assert_eq!(slices.as_contiguous(), Some(&[0, 1, 2, 3][..]));
+}
+
+#[test]
+fn test_vmem_len_kept() {
+    // Not a multiple of the page size: the length is kept, so the buffer falls back to a plain allocation.
+    let buf = ConcurrentVmemRB::from(vec![0usize; 10]);
+    assert!(!buf.is_mapped());
+
+    let (mut prod, mut cons) = buf.split();
+    assert!(!prod.is_double_mapped());
+    assert_eq!(cons.buf_len(), 10);
+
+    push_wrapping(&mut prod, &mut cons);
+    assert_eq!(cons.peek_available().unwrap().as_slices().1, [2, 3]);
+}
+
+#[test]
+fn test_vmem_from_drops_given_items() {
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+
+    let buf = ConcurrentVmemRB::from(vec![value.clone(); 10]);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let (mut prod, cons) = buf.split();
+    prod.push(value.clone()).unwrap();
+
+    drop(prod);
+    drop(cons);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_vmem_odd_item_size() {
+    // 12-byte items never fill a page exactly, so a suitable length is a multiple of an lcm.
+    let len = mutringbuf::vmem_helper::get_page_len_mul::<[u32; 3]>(10);
+    assert_eq!(len * 12 % mutringbuf::vmem_helper::page_size(), 0);
+    let buf = ConcurrentVmemRB::<[u32; 3]>::new_zeroed(len);
+
+    let (mut prod, mut cons) = buf.split();
+    assert!(prod.is_double_mapped());
+    assert_eq!(cons.buf_len(), len);
+
+    for _ in 0..len - 1 {
+        prod.push([0; 3]).unwrap();
+    }
+    unsafe { cons.advance(len - 1) };
+    prod.push_slice(&[[1; 3], [2; 3], [3; 3]]).unwrap();
+
+    assert_eq!(
+        cons.peek_available().unwrap().as_contiguous(),
+        Some(&[[1; 3], [2; 3], [3; 3]][..])
+    );
+}
+
+#[test]
+fn test_vmem_coexistence() {
+    let (mut prod, mut cons) = ConcurrentHeapRB::from(vec![0usize; 10]).split();
+    assert!(!prod.is_double_mapped());
+    push_wrapping(&mut prod, &mut cons);
+    assert_eq!(cons.peek_available().unwrap().as_slices().1, [2, 3]);
+
+    let mut buf = ConcurrentStackRB::from([0usize; 10]);
+    let (mut prod, mut cons) = buf.split();
+    assert!(!prod.is_double_mapped());
+    push_wrapping(&mut prod, &mut cons);
+    assert_eq!(cons.peek_available().unwrap().as_slices().1, [2, 3]);
+}
@@ -0,0 +1,113 @@
+use crate::{common_def, get_buf};
+use mutringbuf::{FollowerIter, MRBIterator};
+
+common_def!();
+
+#[test]
+fn test_peek_at() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    // Move indices near the end of the buffer, so that data wrap around.
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    for i in 0..6 {
+        assert_eq!(cons.peek_at(i), Some(&i));
+    }
+    assert_eq!(cons.peek_at(6), None);
+    assert_eq!(cons.peek_at(usize::MAX), None);
+
+    // Peeking does not move the iterator.
+    assert_eq!(cons.available(), 6);
+    assert_eq!(cons.pop(), Some(0));
+    assert_eq!(cons.peek_at(0), Some(&1));
+}
+
+#[test]
+fn test_peek_window() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut cons) = buf.split();
+
+    assert!(cons.peek_window().is_none());
+
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    cons.reset_index();
+
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    let window = cons.peek_window().unwrap();
+
+    assert_eq!(window.len(), 6);
+    assert!(!window.is_empty());
+    assert_eq!(window[0], 0);
+    assert_eq!(window[5], 5);
+    assert_eq!(window.get(6), None);
+    assert_eq!(
+        window.iter().copied().collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4, 5]
+    );
+
+    let (head, tail) = window.as_slices();
+    assert_eq!(head.len() + tail.len(), 6);
+}
+
+#[test]
+fn test_workable_at() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    for _ in 0..BUFFER_SIZE - 3 {
+        prod.push(0).unwrap();
+    }
+    unsafe { work.advance(BUFFER_SIZE - 3) };
+    cons.reset_index();
+
+    for i in 0..6 {
+        prod.push(i).unwrap();
+    }
+
+    *work.get_workable_at(4).unwrap() += 10;
+    assert!(work.get_workable_at(6).is_none());
+
+    let mut window = work.get_workable_window().unwrap();
+    window[0] += 10;
+    for x in window.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(window.len(), 6);
+
+    unsafe { work.advance(6) };
+
+    for i in [20, 2, 4, 6, 28, 10] {
+        assert_eq!(cons.pop(), Some(i));
+    }
+}
+
+#[test]
+fn test_detached_window() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, cons) = buf.split();
+
+    let mut cons = cons.detach();
+
+    for i in 0..4 {
+        prod.push(i).unwrap();
+    }
+
+    unsafe { cons.advance(1) };
+    assert_eq!(cons.peek_at(2), Some(&3));
+    assert_eq!(cons.peek_window().unwrap().len(), 3);
+    assert_eq!(*cons.get_workable_at(0).unwrap(), 1);
+    assert_eq!(cons.get_workable_window().unwrap()[2], 3);
+}
@@ -0,0 +1,78 @@
+use crate::{common_def, get_buf};
+use mutringbuf::MRBIterator;
+
+common_def!();
+
+#[test]
+fn test_windows() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    // Move indices near the end of the buffer, so that windows wrap around.
+    for _ in 0..BUFFER_SIZE - 5 {
+        prod.push(0).unwrap();
+    }
+    unsafe { work.advance(BUFFER_SIZE - 5) };
+    cons.reset_index();
+
+    for i in 0..10 {
+        prod.push(i).unwrap();
+    }
+
+    let mut windows = work.windows(4, 2);
+    assert_eq!((windows.size(), windows.hop()), (4, 2));
+
+    let mut firsts = vec![];
+    while let Some(mut window) = windows.next_window() {
+        assert_eq!(window.len(), 4);
+        firsts.push(window[0]);
+
+        window[3] += 100;
+    }
+    // Windows at 0, 2, 4, 6: the last two items are not enough for another window.
+    assert_eq!(firsts, [0, 2, 4, 6]);
+    drop(windows);
+
+    // The worker has moved by one hop per window.
+    assert_eq!(work.available(), 2);
+    assert_eq!(cons.available(), 8);
+
+    for i in [0, 1, 2, 103, 4, 105, 6, 107] {
+        assert_eq!(cons.pop(), Some(i));
+    }
+}
+
+#[test]
+fn test_windows_retain_overlap() {
+    let mut buf = get_buf!(Concurrent);
+    let (mut prod, mut work, mut cons) = buf.split_mut();
+
+    let mut windows = work.windows(4, 1);
+    assert!(windows.next_window().is_none());
+
+    for i in 0..4 {
+        prod.push(i).unwrap();
+    }
+    assert_eq!(windows.next_window().unwrap()[0], 0);
+    assert!(windows.next_window().is_none());
+    drop(windows);
+
+    // Only the first item has been released to the consumer.
+    assert_eq!(cons.available(), 1);
+    // Retained items cannot be overwritten by the producer.
+    assert_eq!(prod.available(), BUFFER_SIZE - 1 - 4);
+
+    // A new item makes a new window available.
+    prod.push(4).unwrap();
+    let mut windows = work.windows(4, 1);
+    assert_eq!(windows.next_window().unwrap()[3], 4);
+}
+
+#[test]
+#[should_panic]
+fn test_windows_hop_too_large() {
+    let mut buf = get_buf!(Concurrent);
+    let (_, mut work, _) = buf.split_mut();
+
+    work.windows(2, 3);
+}
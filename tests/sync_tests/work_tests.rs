@@ -2,7 +2,7 @@ extern crate alloc;
 
 use crate::{common_def, get_buf};
 use mutringbuf::iterators::ProdIter;
-use mutringbuf::{MRBIterator, MutRB};
+use mutringbuf::{FollowerIter, MRBIterator, MutRB};
 
 common_def!();
 
@@ -62,11 +62,10 @@ fn test_work_mul() {
     assert_eq!(work.available(), BUFFER_SIZE - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some((h, t)) = work.get_workable_slice_multiple_of(MULTIPLE) {
-        let len = h.len() + t.len();
+    if let Some(mut slices) = work.get_workable_slice_multiple_of(MULTIPLE) {
+        let len = slices.len();
 
-        h.iter_mut().for_each(|v| *v += 1);
-        t.iter_mut().for_each(|v| *v += 1);
+        slices.iter_mut().for_each(|v| *v += 1);
 
         unsafe { work.advance(len) };
     }
@@ -88,11 +87,10 @@ fn test_work_mul() {
     assert_eq!(work.available(), rem - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some((h, t)) = work.get_workable_slice_avail() {
-        let len = h.len() + t.len();
+    if let Some(mut slices) = work.get_workable_slice_avail() {
+        let len = slices.len();
 
-        h.iter_mut().for_each(|v| *v += 1);
-        t.iter_mut().for_each(|v| *v += 1);
+        slices.iter_mut().for_each(|v| *v += 1);
 
         unsafe { work.advance(len) };
     }
@@ -127,11 +125,10 @@ fn test_work_exact() {
     let step = 10;
     let max = 30;
     for _ in 0..max {
-        if let Some((h, t)) = work.get_workable_slice_exact(step) {
-            let len = h.len() + t.len();
+        if let Some(mut slices) = work.get_workable_slice_exact(step) {
+            let len = slices.len();
 
-            h.iter_mut().for_each(|v| *v += 1);
-            t.iter_mut().for_each(|v| *v += 1);
+            slices.iter_mut().for_each(|v| *v += 1);
 
             unsafe { work.advance(len) };
         }
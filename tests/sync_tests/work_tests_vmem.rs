@@ -2,7 +2,7 @@ extern crate alloc;
 
 use crate::{common_def, get_buf};
 use mutringbuf::iterators::ProdIter;
-use mutringbuf::{MRBIterator, MutRB};
+use mutringbuf::{FollowerIter, MRBIterator, MutRB};
 
 common_def!();
 const MULTIPLE: usize = 42;
@@ -59,7 +59,7 @@ fn test_work_mul() {
     assert_eq!(work.available(), BUFFER_SIZE - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some(res) = work.get_workable_slice_multiple_of(MULTIPLE) {
+    if let Some(mut res) = work.get_workable_slice_multiple_of(MULTIPLE) {
         res.iter_mut().for_each(|v| *v += 1);
         unsafe { work.advance(res.len()) };
     }
@@ -80,7 +80,7 @@ fn test_work_mul() {
     assert_eq!(work.available(), rem - 1);
     assert_eq!(cons.available(), 0);
 
-    if let Some(res) = work.get_workable_slice_avail() {
+    if let Some(mut res) = work.get_workable_slice_avail() {
         res.iter_mut().for_each(|v| *v += 1);
         unsafe { work.advance(res.len()) };
     }
@@ -114,7 +114,7 @@ fn test_work_exact() {
     let step = 10;
     let max = 30;
     for _ in 0..max {
-        if let Some(res) = work.get_workable_slice_exact(step) {
+        if let Some(mut res) = work.get_workable_slice_exact(step) {
             res.iter_mut().for_each(|v| *v += 1);
             unsafe { work.advance(res.len()) };
         }
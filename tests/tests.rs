@@ -32,11 +32,10 @@ macro_rules! common_def {
 #[cfg(feature = "vmem")]
 macro_rules! get_buf {
     (Local) => {
-        #[cfg(feature = "vmem")]
-        mutringbuf::LocalHeapRB::from(vec![0; BUFFER_SIZE])
+        mutringbuf::LocalVmemRB::from(vec![0; BUFFER_SIZE])
     };
     (Concurrent) => {
-        mutringbuf::ConcurrentHeapRB::from(vec![0; BUFFER_SIZE])
+        mutringbuf::ConcurrentVmemRB::from(vec![0; BUFFER_SIZE])
     };
 }
 #[cfg(not(feature = "vmem"))]
@@ -53,10 +52,9 @@ pub(crate) use {common_def, get_buf};
 #[test]
 #[should_panic]
 fn len_zero_heap() {
-    let _ = mutringbuf::ConcurrentHeapRB::<i32>::default(0);
+    let _ = mutringbuf::ConcurrentHeapRB::<i32>::new_zeroed(0);
 }
 
-#[cfg(not(feature = "vmem"))]
 #[test]
 #[should_panic]
 fn len_zero_stack() {